@@ -1,3 +1,13 @@
+pub mod bipartite;
+pub mod coloring;
 pub mod connected_components;
+pub mod connectivity;
 pub mod cycles;
+pub mod euler;
+pub mod flow;
+pub mod matching;
 pub mod minimum_spanning_tree;
+pub mod shortest_paths;
+pub mod topological_sort;
+pub mod tsp;
+pub mod vertex_cover;
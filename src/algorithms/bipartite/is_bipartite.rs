@@ -0,0 +1,182 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::graph::{
+    graph::{Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Bipartiteness {
+    Bipartite(HashSet<Vertex>, HashSet<Vertex>),
+    OddCycle(Path<Vertex, Weight>),
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Bipartiteness {
+    is_bipartite(g)
+}
+
+/// Two-colors every component with a BFS. The smallest vertex of each
+/// component goes on the first side; a monochromatic edge closes an odd
+/// cycle through the BFS tree, which is returned instead.
+pub fn is_bipartite(g: &dyn Graph<Vertex, Weight>) -> Bipartiteness {
+    let mut parents: HashMap<Vertex, (Vertex, Weight)> = HashMap::new();
+    let mut depths: HashMap<Vertex, usize> = HashMap::new();
+
+    let mut roots: Vec<&Vertex> = g.get_vertices().iter().collect();
+    roots.sort();
+
+    for root in roots {
+        if depths.contains_key(root) {
+            continue;
+        }
+
+        depths.insert(*root, 0);
+        let mut queue = VecDeque::from([*root]);
+        while let Some(u) = queue.pop_front() {
+            for (v, w) in g._get_adj_list(&u).unwrap() {
+                match depths.get(v) {
+                    None => {
+                        depths.insert(*v, depths[&u] + 1);
+                        parents.insert(*v, (u, *w));
+                        queue.push_back(*v);
+                    }
+                    Some(d) if d % 2 == depths[&u] % 2 => {
+                        return Bipartiteness::OddCycle(odd_cycle(&parents, &depths, (u, *v, *w)));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let side = |parity: usize| {
+        depths
+            .iter()
+            .filter(|(_, d)| *d % 2 == parity)
+            .map(|(v, _)| *v)
+            .collect()
+    };
+
+    Bipartiteness::Bipartite(side(0), side(1))
+}
+
+fn odd_cycle(
+    parents: &HashMap<Vertex, (Vertex, Weight)>,
+    depths: &HashMap<Vertex, usize>,
+    e: (Vertex, Vertex, Weight),
+) -> Path<Vertex, Weight> {
+    let (mut a, mut b) = (e.0, e.1);
+    let mut from_u = vec![];
+    let mut from_v = vec![];
+
+    while a != b {
+        if depths[&a] >= depths[&b] {
+            let (p, w) = parents[&a];
+            from_u.push((p, a, w));
+            a = p;
+        } else {
+            let (p, w) = parents[&b];
+            from_v.push((b, p, w));
+            b = p;
+        }
+    }
+
+    let mut cycle: Path<Vertex, Weight> = from_u.into_iter().rev().collect();
+    cycle.push(e);
+    cycle.extend(from_v);
+
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    fn is_closed_walk(cycle: &Path<Vertex, Weight>) -> bool {
+        (0..cycle.len()).all(|i| cycle[i].1 == cycle[(i + 1) % cycle.len()].0)
+    }
+
+    #[test]
+    fn bipartite_graph() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 1, 1);
+        g.add_edge(4, 5, 1);
+
+        let expected = Bipartiteness::Bipartite(HashSet::from([1, 3, 5]), HashSet::from([2, 4]));
+        let current = is_bipartite(&g);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn non_connected_bipartite_graph() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(7, 5, 1);
+        g.add_edge(5, 6, 1);
+
+        let expected = Bipartiteness::Bipartite(HashSet::from([1, 3, 5]), HashSet::from([2, 6, 7]));
+        let current = is_bipartite(&g);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn odd_cycle() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, 3);
+        g.add_edge(4, 5, 4);
+        g.add_edge(5, 1, 5);
+        g.add_edge(5, 6, 6);
+
+        match is_bipartite(&g) {
+            Bipartiteness::OddCycle(cycle) => {
+                assert_eq!(5, cycle.len());
+                assert!(is_closed_walk(&cycle));
+                for (u, v, w) in cycle {
+                    assert_eq!(Some(&w), g.get_weight(&u, &v));
+                }
+            }
+            _ => panic!("Expected an odd cycle"),
+        }
+    }
+
+    #[test]
+    fn odd_cycle_in_second_component() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 6, 1);
+        g.add_edge(6, 4, 1);
+
+        match is_bipartite(&g) {
+            Bipartiteness::OddCycle(cycle) => {
+                assert_eq!(3, cycle.len());
+                assert!(is_closed_walk(&cycle));
+            }
+            _ => panic!("Expected an odd cycle"),
+        }
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 2, 3);
+
+        let expected = Bipartiteness::OddCycle(vec![(2, 2, 3)]);
+        let current = is_bipartite(&g);
+        assert_eq!(expected, current);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dsatur;
+pub mod exact;
+pub mod greedy;
+pub mod proper_coloring;
@@ -0,0 +1,104 @@
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{
+    algorithms::coloring::proper_coloring::Coloring,
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Coloring {
+    dsatur(g)
+}
+
+/// Brélaz's heuristic: always colors next the vertex whose neighbours already
+/// use the most distinct colors, breaking ties by degree.
+pub fn dsatur(g: &dyn Graph<Vertex, Weight>) -> Coloring {
+    let mut colors: HashMap<Vertex, usize> = HashMap::new();
+    let mut neighbour_colors: HashMap<Vertex, HashSet<usize>> = HashMap::new();
+    let mut queue: BTreeSet<(usize, usize, Reverse<Vertex>)> = g
+        .get_vertices()
+        .iter()
+        .map(|v| (0, g._get_adj_list(v).unwrap().len(), Reverse(*v)))
+        .collect();
+
+    while let Some((_, _, Reverse(u))) = queue.pop_last() {
+        let used = neighbour_colors.remove(&u).unwrap_or_default();
+        let color = (0..).find(|c| !used.contains(c)).unwrap();
+        colors.insert(u, color);
+
+        for v in g._get_adj_list(&u).unwrap().keys() {
+            if colors.contains_key(v) {
+                continue;
+            }
+
+            let saturation = neighbour_colors.entry(*v).or_default();
+            let degree = g._get_adj_list(v).unwrap().len();
+            if !saturation.contains(&color) {
+                queue.remove(&(saturation.len(), degree, Reverse(*v)));
+                saturation.insert(color);
+                queue.insert((saturation.len(), degree, Reverse(*v)));
+            }
+        }
+    }
+
+    Coloring::from(colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::coloring::proper_coloring::is_proper_coloring,
+        graph::undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn dsatur_bipartite_graph() {
+        let mut g = UndirectedGraph::new();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                if i != j {
+                    g.add_edge(2 * i, 2 * j + 1, 1);
+                }
+            }
+        }
+
+        let current = dsatur(&g);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(2, current.num_colors);
+    }
+
+    #[test]
+    fn dsatur_odd_cycle() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 1, 1);
+
+        let current = dsatur(&g);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(3, current.num_colors);
+    }
+
+    #[test]
+    fn dsatur_complete_graph() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..6 {
+            for v in u + 1..6 {
+                g.add_edge(u, v, 1);
+            }
+        }
+
+        let current = dsatur(&g);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(6, current.num_colors);
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::coloring::{dsatur::dsatur, proper_coloring::Coloring},
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Coloring {
+    exact(g)
+}
+
+/// Coloring with the chromatic number of colors, found by branch and bound.
+/// DSatur gives the first upper bound and a greedy clique the lower bound, so
+/// the search is exponential only in the gap between them: use it on small
+/// graphs.
+pub fn exact(g: &dyn Graph<Vertex, Weight>) -> Coloring {
+    let best = dsatur(g);
+
+    let mut vertices: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+    vertices.sort();
+    let indexes: HashMap<Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+    let adj: Vec<Vec<usize>> = vertices
+        .iter()
+        .map(|u| {
+            g._get_adj_list(u)
+                .unwrap()
+                .keys()
+                .filter(|v| *v != u)
+                .map(|v| indexes[v])
+                .collect()
+        })
+        .collect();
+
+    let lower_bound = greedy_clique(&adj);
+    if best.num_colors <= lower_bound {
+        return best;
+    }
+
+    let mut search = BranchAndBound {
+        adj: &adj,
+        colors: vec![None; vertices.len()],
+        best: vertices.iter().map(|v| best.colors[v]).collect(),
+        best_num_colors: best.num_colors,
+        lower_bound,
+    };
+    search.branch(0, 0);
+
+    Coloring::from(
+        vertices
+            .iter()
+            .zip(search.best)
+            .map(|(v, c)| (*v, c))
+            .collect(),
+    )
+}
+
+struct BranchAndBound<'a> {
+    adj: &'a Vec<Vec<usize>>,
+    colors: Vec<Option<usize>>,
+    best: Vec<usize>,
+    best_num_colors: usize,
+    lower_bound: usize,
+}
+
+impl BranchAndBound<'_> {
+    fn branch(&mut self, colored: usize, num_colors: usize) {
+        if num_colors >= self.best_num_colors {
+            return;
+        }
+        if colored == self.colors.len() {
+            self.best = self.colors.iter().map(|c| c.unwrap()).collect();
+            self.best_num_colors = num_colors;
+            return;
+        }
+
+        let u = self.most_saturated();
+        for c in 0..=num_colors {
+            if c + 1 >= self.best_num_colors || self.best_num_colors == self.lower_bound {
+                break;
+            }
+            if self.adj[u].iter().any(|v| self.colors[*v] == Some(c)) {
+                continue;
+            }
+
+            self.colors[u] = Some(c);
+            self.branch(colored + 1, num_colors.max(c + 1));
+            self.colors[u] = None;
+        }
+    }
+
+    fn most_saturated(&self) -> usize {
+        (0..self.colors.len())
+            .filter(|u| self.colors[*u].is_none())
+            .max_by_key(|u| {
+                let mut used: Vec<usize> = self.adj[*u]
+                    .iter()
+                    .filter_map(|v| self.colors[*v])
+                    .collect();
+                used.sort();
+                used.dedup();
+
+                (used.len(), self.adj[*u].len(), std::cmp::Reverse(*u))
+            })
+            .unwrap()
+    }
+}
+
+fn greedy_clique(adj: &[Vec<usize>]) -> usize {
+    let mut order: Vec<usize> = (0..adj.len()).collect();
+    order.sort_by_key(|u| std::cmp::Reverse(adj[*u].len()));
+
+    order
+        .iter()
+        .map(|u| {
+            let mut clique = vec![*u];
+            for v in &order {
+                if clique.iter().all(|w| adj[*v].contains(w)) {
+                    clique.push(*v);
+                }
+            }
+            clique.len()
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::coloring::proper_coloring::is_proper_coloring,
+        graph::generators::{rng::Rng, testing::random_edges},
+        graph::undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn exact_petersen_graph() {
+        let mut g = UndirectedGraph::new();
+
+        for i in 0..5 {
+            g.add_edge(i, (i + 1) % 5, 1);
+            g.add_edge(i, i + 5, 1);
+            g.add_edge(i + 5, (i + 2) % 5 + 5, 1);
+        }
+
+        let current = exact(&g);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(3, current.num_colors);
+    }
+
+    #[test]
+    fn exact_matches_brute_force() {
+        let mut rng = Rng::new(7);
+        for _ in 0..10 {
+            let mut g = UndirectedGraph::new();
+            random_edges(&mut g, 6, 20, &(1..=1), &mut rng);
+
+            let vertices: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+            let chromatic_number = (1..=vertices.len())
+                .find(|k| {
+                    (0..k.pow(vertices.len() as u32)).any(|mut code| {
+                        let colors = vertices
+                            .iter()
+                            .map(|v| {
+                                let c = code % k;
+                                code /= k;
+                                (*v, c)
+                            })
+                            .collect();
+                        is_proper_coloring(&g, &Coloring::from(colors))
+                    })
+                })
+                .unwrap_or(0);
+
+            let current = exact(&g);
+            assert!(is_proper_coloring(&g, &current));
+            assert_eq!(chromatic_number, current.num_colors);
+        }
+    }
+
+    #[test]
+    fn exact_mycielski_graph() {
+        // Grötzsch graph: triangle-free, chromatic number 4
+        let mut g = UndirectedGraph::new();
+
+        for i in 0..5 {
+            g.add_edge(i, (i + 1) % 5, 1);
+            g.add_edge(i + 5, (i + 1) % 5, 1);
+            g.add_edge(i + 5, (i + 4) % 5, 1);
+            g.add_edge(i + 5, 10, 1);
+        }
+
+        let current = exact(&g);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(4, current.num_colors);
+    }
+
+    #[test]
+    fn exact_empty_graph() {
+        let g = UndirectedGraph::new();
+
+        let current = exact(&g);
+        assert_eq!(0, current.num_colors);
+    }
+}
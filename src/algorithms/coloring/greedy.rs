@@ -0,0 +1,156 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::{
+    algorithms::coloring::proper_coloring::Coloring,
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VertexOrder {
+    Natural,
+    LargestFirst,
+    SmallestLast,
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, order: VertexOrder) -> Coloring {
+    greedy(g, order)
+}
+
+/// Colors the vertices one at a time in the given order, each with the
+/// smallest color not used by its neighbours.
+pub fn greedy(g: &dyn Graph<Vertex, Weight>, order: VertexOrder) -> Coloring {
+    let mut colors: HashMap<Vertex, usize> = HashMap::new();
+
+    for u in vertex_order(g, order) {
+        let used: HashSet<usize> = g
+            ._get_adj_list(&u)
+            .unwrap()
+            .keys()
+            .filter_map(|v| colors.get(v).cloned())
+            .collect();
+        let color = (0..).find(|c| !used.contains(c)).unwrap();
+
+        colors.insert(u, color);
+    }
+
+    Coloring::from(colors)
+}
+
+pub fn vertex_order(g: &dyn Graph<Vertex, Weight>, order: VertexOrder) -> Vec<Vertex> {
+    let mut vertices: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+    vertices.sort();
+
+    match order {
+        VertexOrder::Natural => vertices,
+        VertexOrder::LargestFirst => {
+            vertices.sort_by_key(|v| std::cmp::Reverse(degree(g, v)));
+            vertices
+        }
+        VertexOrder::SmallestLast => smallest_last(g, &vertices),
+    }
+}
+
+fn smallest_last(g: &dyn Graph<Vertex, Weight>, vertices: &[Vertex]) -> Vec<Vertex> {
+    let mut degrees: HashMap<Vertex, usize> = vertices.iter().map(|v| (*v, degree(g, v))).collect();
+    let mut queue: BTreeSet<(usize, Vertex)> = degrees.iter().map(|(v, d)| (*d, *v)).collect();
+    let mut order = vec![];
+
+    while let Some((_, u)) = queue.pop_first() {
+        degrees.remove(&u);
+        order.push(u);
+
+        for v in g._get_adj_list(&u).unwrap().keys() {
+            if let Some(d) = degrees.get_mut(v) {
+                queue.remove(&(*d, *v));
+                *d -= 1;
+                queue.insert((*d, *v));
+            }
+        }
+    }
+    order.reverse();
+
+    order
+}
+
+fn degree(g: &dyn Graph<Vertex, Weight>, v: &Vertex) -> usize {
+    let adj_list = g._get_adj_list(v).unwrap();
+
+    adj_list.len() - usize::from(adj_list.contains_key(v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::coloring::proper_coloring::is_proper_coloring,
+        graph::undirected_graph::UndirectedGraph,
+    };
+
+    // K(n, n) minus a perfect matching: the natural order alternates sides
+    // and needs one color per pair.
+    fn crown(n: usize) -> UndirectedGraph<Vertex, Weight> {
+        let mut g = UndirectedGraph::new();
+
+        for i in 0..n {
+            for j in 0..n {
+                if i != j {
+                    g.add_edge(2 * i, 2 * j + 1, 1);
+                }
+            }
+        }
+
+        g
+    }
+
+    #[test]
+    fn greedy_natural_order() {
+        let g = crown(4);
+
+        let current = greedy(&g, VertexOrder::Natural);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(4, current.num_colors);
+    }
+
+    #[test]
+    fn greedy_smallest_last_order() {
+        let g = crown(4);
+
+        let current = greedy(&g, VertexOrder::SmallestLast);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(2, current.num_colors);
+    }
+
+    #[test]
+    fn greedy_largest_first_order() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 1, 1);
+        g.add_edge(6, 1, 1);
+
+        let current = greedy(&g, VertexOrder::LargestFirst);
+        assert!(is_proper_coloring(&g, &current));
+        assert_eq!(3, current.num_colors);
+        assert_eq!(Some(&0), current.colors.get(&1));
+    }
+
+    #[test]
+    fn vertex_order_smallest_last() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+
+        let expected = vec![3, 2, 1, 4];
+        let current = vertex_order(&g, VertexOrder::SmallestLast);
+        assert_eq!(expected, current);
+    }
+}
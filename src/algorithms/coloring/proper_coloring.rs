@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Coloring {
+    pub colors: HashMap<Vertex, usize>,
+    pub num_colors: usize,
+}
+
+impl Coloring {
+    pub fn from(colors: HashMap<Vertex, usize>) -> Coloring {
+        let num_colors = colors.values().max().map_or(0, |c| c + 1);
+
+        Coloring { colors, num_colors }
+    }
+}
+
+/// Every vertex has a color and no edge joins two vertices of the same color.
+pub fn is_proper_coloring(g: &dyn Graph<Vertex, Weight>, coloring: &Coloring) -> bool {
+    let colored = g.get_vertices().iter().all(|v| {
+        coloring
+            .colors
+            .get(v)
+            .is_some_and(|c| *c < coloring.num_colors)
+    });
+
+    colored
+        && g.get_edges()
+            .iter()
+            .all(|(u, v, _)| coloring.colors[u] != coloring.colors[v])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    fn triangle() -> UndirectedGraph<Vertex, Weight> {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+
+        g
+    }
+
+    #[test]
+    fn proper_coloring() {
+        let coloring = Coloring::from(HashMap::from([(1, 0), (2, 1), (3, 2)]));
+
+        assert_eq!(3, coloring.num_colors);
+        assert!(is_proper_coloring(&triangle(), &coloring));
+    }
+
+    #[test]
+    fn coloring_with_conflict() {
+        let coloring = Coloring::from(HashMap::from([(1, 0), (2, 1), (3, 1)]));
+
+        assert!(!is_proper_coloring(&triangle(), &coloring));
+    }
+
+    #[test]
+    fn coloring_with_uncolored_vertex() {
+        let coloring = Coloring::from(HashMap::from([(1, 0), (2, 1)]));
+
+        assert!(!is_proper_coloring(&triangle(), &coloring));
+    }
+}
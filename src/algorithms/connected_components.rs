@@ -1 +1,4 @@
 pub mod count_connected_components;
+pub mod label_connected_components;
+pub mod strongly_connected_components;
+pub mod weakly_connected_components;
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    label_connected_components(g)
+}
+
+/// Component label of every vertex. Components are numbered from 0 in the
+/// order of their smallest vertex.
+pub fn label_connected_components(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    let mut labels: HashMap<Vertex, usize> = HashMap::new();
+    let mut count = 0;
+
+    let mut vertices: Vec<&Vertex> = g.get_vertices().iter().collect();
+    vertices.sort();
+
+    for root in vertices {
+        if labels.contains_key(root) {
+            continue;
+        }
+
+        let label = count;
+        count += 1;
+        labels.insert(*root, label);
+        let mut stack = vec![*root];
+        while let Some(u) = stack.pop() {
+            for v in g._get_adj_list(&u).unwrap().keys() {
+                if !labels.contains_key(v) {
+                    labels.insert(*v, label);
+                    stack.push(*v);
+                }
+            }
+        }
+    }
+
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    #[test]
+    fn label_connected_components_several_components() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(4, 5, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(9, 7, 1);
+
+        let expected = HashMap::from([(1, 0), (2, 0), (3, 0), (4, 1), (5, 1), (7, 2), (9, 2)]);
+        let current = label_connected_components(&g);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn label_connected_components_one_component() {
+        let mut g = UndirectedGraph::new();
+
+        for v in 1..100 {
+            g.add_edge(v, v + 1, 1);
+        }
+
+        let current = label_connected_components(&g);
+        assert_eq!(100, current.len());
+        assert!(current.values().all(|l| *l == 0));
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use crate::graph::{
+    directed_graph::DirectedGraph,
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    tarjan(g)
+}
+
+/// Component label of every vertex, found with Tarjan's algorithm.
+/// Components are numbered from 0 in the order of their smallest vertex.
+pub fn tarjan(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    let mut vertices: Vec<Vertex> = g.get_vertices().iter().copied().collect();
+    vertices.sort();
+
+    labels(tarjan_components(&vertices, |u| {
+        g._get_adj_list(u).unwrap().keys().copied().collect()
+    }))
+}
+
+/// Tarjan's algorithm over the graph that `neighbours` describes, restricted
+/// to the vertices it returns. Iterative; components come out in reverse
+/// topological order of the condensation.
+pub fn tarjan_components(
+    vertices: &[Vertex],
+    neighbours: impl Fn(&Vertex) -> Vec<Vertex>,
+) -> Vec<Vec<Vertex>> {
+    let mut index = 0;
+    let mut indexes: HashMap<Vertex, usize> = HashMap::new();
+    let mut low: HashMap<Vertex, usize> = HashMap::new();
+    let mut stack: Vec<Vertex> = vec![];
+    let mut on_stack: HashSet<Vertex> = HashSet::new();
+    let mut components = vec![];
+
+    for root in vertices {
+        if indexes.contains_key(root) {
+            continue;
+        }
+
+        indexes.insert(*root, index);
+        low.insert(*root, index);
+        index += 1;
+        stack.push(*root);
+        on_stack.insert(*root);
+        let mut calls = vec![(*root, neighbours(root), 0)];
+
+        while let Some((u, adj_list, i)) = calls.last_mut() {
+            let u = *u;
+            if *i < adj_list.len() {
+                let v = adj_list[*i];
+                *i += 1;
+
+                if let Entry::Vacant(e) = indexes.entry(v) {
+                    e.insert(index);
+                    low.insert(v, index);
+                    index += 1;
+                    stack.push(v);
+                    on_stack.insert(v);
+                    calls.push((v, neighbours(&v), 0));
+                } else if on_stack.contains(&v) {
+                    low.insert(u, low[&u].min(indexes[&v]));
+                }
+            } else {
+                calls.pop();
+                if let Some((p, _, _)) = calls.last() {
+                    low.insert(*p, low[p].min(low[&u]));
+                }
+
+                if low[&u] == indexes[&u] {
+                    let mut component = vec![];
+                    loop {
+                        let v = stack.pop().unwrap();
+                        on_stack.remove(&v);
+                        component.push(v);
+                        if v == u {
+                            break;
+                        }
+                    }
+                    components.push(component);
+                }
+            }
+        }
+    }
+
+    components
+}
+
+/// Same labels as `tarjan`, found with Kosaraju's two passes: vertices by
+/// decreasing DFS finishing time, then a search of the reversed graph from
+/// each of them.
+pub fn kosaraju(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    let mut vertices: Vec<Vertex> = g.get_vertices().iter().copied().collect();
+    vertices.sort();
+
+    let mut finished: Vec<Vertex> = vec![];
+    let mut visited: HashSet<Vertex> = HashSet::new();
+    for root in &vertices {
+        if !visited.insert(*root) {
+            continue;
+        }
+
+        let mut calls = vec![(
+            *root,
+            g._get_adj_list(root)
+                .unwrap()
+                .keys()
+                .copied()
+                .collect::<Vec<_>>(),
+        )];
+        while let Some((u, adj_list)) = calls.last_mut() {
+            match adj_list.pop() {
+                Some(v) => {
+                    if visited.insert(v) {
+                        calls.push((v, g._get_adj_list(&v).unwrap().keys().copied().collect()));
+                    }
+                }
+                None => {
+                    finished.push(*u);
+                    calls.pop();
+                }
+            }
+        }
+    }
+
+    let mut reversed: HashMap<Vertex, Vec<Vertex>> = HashMap::new();
+    for u in &vertices {
+        for v in g._get_adj_list(u).unwrap().keys() {
+            reversed.entry(*v).or_default().push(*u);
+        }
+    }
+
+    let mut components = vec![];
+    let mut assigned: HashSet<Vertex> = HashSet::new();
+    for root in finished.iter().rev() {
+        if !assigned.insert(*root) {
+            continue;
+        }
+
+        let mut component = vec![*root];
+        let mut stack = vec![*root];
+        while let Some(u) = stack.pop() {
+            for v in reversed.get(&u).into_iter().flatten() {
+                if assigned.insert(*v) {
+                    component.push(*v);
+                    stack.push(*v);
+                }
+            }
+        }
+        components.push(component);
+    }
+
+    labels(components)
+}
+
+fn labels(mut components: Vec<Vec<Vertex>>) -> HashMap<Vertex, usize> {
+    components.sort_by_key(|c| *c.iter().min().unwrap());
+
+    components
+        .iter()
+        .enumerate()
+        .flat_map(|(label, c)| c.iter().map(move |v| (*v, label)))
+        .collect()
+}
+
+/// Condensation of `g` under `labels`: a DAG on the component labels with an
+/// edge wherever an edge of `g` joins two components, weighing the least of
+/// them. Components with no such edge are not in the graph.
+pub fn condensation(
+    g: &dyn Graph<Vertex, Weight>,
+    labels: &HashMap<Vertex, usize>,
+) -> DirectedGraph<Vertex, Weight> {
+    let mut dag = DirectedGraph::new();
+
+    for (u, v, w) in g.get_edges() {
+        let (cu, cv) = (labels[u], labels[v]);
+        if cu != cv && dag.get_weight(&cu, &cv).is_none_or(|old| w < old) {
+            dag.add_edge(cu, cv, *w);
+        }
+    }
+
+    dag
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{
+        generators::{rng::Rng, testing::random_edges},
+        undirected_graph::UndirectedGraph,
+    };
+
+    fn example() -> DirectedGraph<Vertex, Weight> {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 1, 3);
+        g.add_edge(3, 4, 4);
+        g.add_edge(2, 4, 7);
+        g.add_edge(4, 5, 5);
+        g.add_edge(5, 6, 6);
+        g.add_edge(6, 4, 7);
+        g.add_edge(6, 7, 8);
+
+        g
+    }
+
+    #[test]
+    fn tarjan_and_kosaraju() {
+        let g = example();
+
+        let expected = HashMap::from([(1, 0), (2, 0), (3, 0), (4, 1), (5, 1), (6, 1), (7, 2)]);
+        assert_eq!(expected, tarjan(&g));
+        assert_eq!(expected, kosaraju(&g));
+    }
+
+    #[test]
+    fn condensation_dag() {
+        let g = example();
+        let dag = condensation(&g, &tarjan(&g));
+
+        let expected = HashSet::from([(0, 1, 4), (1, 2, 8)]);
+        assert_eq!(&expected, dag.get_edges());
+    }
+
+    #[test]
+    fn undirected_components() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 4, 1);
+
+        let expected = HashMap::from([(1, 0), (2, 0), (3, 1), (4, 1)]);
+        assert_eq!(expected, tarjan(&g));
+        assert_eq!(expected, kosaraju(&g));
+    }
+
+    #[test]
+    fn tarjan_matches_kosaraju() {
+        let mut rng = Rng::new(5);
+
+        for _ in 0..100 {
+            let mut g = DirectedGraph::new();
+            random_edges(&mut g, 15, 25, &(1..=1), &mut rng);
+
+            let labels = tarjan(&g);
+            assert_eq!(labels, kosaraju(&g));
+
+            // The condensation is acyclic: its components are single vertices
+            let dag = condensation(&g, &labels);
+            let dag_labels = tarjan(&dag);
+            assert_eq!(
+                dag_labels.len(),
+                dag_labels.values().collect::<HashSet<_>>().len()
+            );
+        }
+    }
+
+    #[test]
+    fn long_cycle() {
+        let mut g = DirectedGraph::new();
+
+        for v in 0..100_000 {
+            g.add_edge(v, (v + 1) % 100_000, 1);
+        }
+
+        assert!(tarjan(&g).values().all(|l| *l == 0));
+        assert!(kosaraju(&g).values().all(|l| *l == 0));
+    }
+}
@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+use crate::algorithms::connected_components::label_connected_components::label_connected_components;
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{UndirectedGraph, Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    weakly_connected_components(g)
+}
+
+/// Components of a directed graph with the direction of its edges ignored,
+/// labelled like `label_connected_components`.
+pub fn weakly_connected_components(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    if !g.is_directed() {
+        return label_connected_components(g);
+    }
+
+    let mut undirected = UndirectedGraph::new();
+    for (u, v, w) in g.get_edges() {
+        undirected.add_edge(*u, *v, *w);
+    }
+
+    label_connected_components(&undirected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::directed_graph::DirectedGraph;
+
+    #[test]
+    fn directions_are_ignored() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 2, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 4, 1);
+
+        let expected = HashMap::from([(1, 0), (2, 0), (3, 0), (4, 1), (5, 1)]);
+        assert_eq!(expected, weakly_connected_components(&g));
+    }
+}
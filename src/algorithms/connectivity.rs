@@ -0,0 +1,5 @@
+pub mod articulation_points;
+pub mod biconnected_components;
+pub mod bridges;
+pub mod low_link;
+pub mod st_connected;
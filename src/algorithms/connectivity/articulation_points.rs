@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::algorithms::connectivity::low_link::low_link;
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> HashSet<Vertex> {
+    articulation_points(g)
+}
+
+/// Vertices whose removal disconnects the graph. A root is one when it has
+/// several DFS children, any other vertex when a child subtree has no back
+/// edge above it.
+pub fn articulation_points(g: &dyn Graph<Vertex, Weight>) -> HashSet<Vertex> {
+    let ll = low_link(g);
+    let mut points = HashSet::new();
+    let mut root_children: HashMap<Vertex, usize> = HashMap::new();
+
+    for (v, p) in &ll.parent {
+        if !ll.parent.contains_key(p) {
+            *root_children.entry(*p).or_insert(0) += 1;
+        } else if ll.low[v] >= ll.discovery[p] {
+            points.insert(*p);
+        }
+    }
+    points.extend(
+        root_children
+            .into_iter()
+            .filter(|(_, children)| *children > 1)
+            .map(|(root, _)| root),
+    );
+
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{
+        generators::{rng::Rng, testing::random_edges},
+        undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn articulation_points_between_cycles() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 6, 1);
+        g.add_edge(6, 7, 1);
+        g.add_edge(7, 5, 1);
+
+        assert_eq!(HashSet::from([3, 4, 5]), articulation_points(&g));
+    }
+
+    #[test]
+    fn root_with_several_children() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(2, 4, 1);
+        g.add_edge(4, 2, 1);
+
+        assert_eq!(HashSet::from([1, 2]), articulation_points(&g));
+    }
+
+    #[test]
+    fn no_articulation_points() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+        g.add_edge(5, 6, 1);
+
+        assert!(articulation_points(&g).is_empty());
+    }
+
+    fn components_without(g: &UndirectedGraph<Vertex, Weight>, removed: Option<Vertex>) -> usize {
+        let mut visited: HashSet<Vertex> = removed.into_iter().collect();
+        let mut count = 0;
+
+        for root in g.get_vertices() {
+            if visited.insert(*root) {
+                count += 1;
+                let mut stack = vec![*root];
+                while let Some(u) = stack.pop() {
+                    for v in g._get_adj_list(&u).unwrap().keys() {
+                        if visited.insert(*v) {
+                            stack.push(*v);
+                        }
+                    }
+                }
+            }
+        }
+
+        count
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(3);
+
+        for _ in 0..200 {
+            let mut g = UndirectedGraph::new();
+            random_edges(&mut g, 9, 12, &(1..=1), &mut rng);
+
+            let components = components_without(&g, None);
+            let expected: HashSet<Vertex> = g
+                .get_vertices()
+                .iter()
+                .filter(|v| components_without(&g, Some(**v)) > components)
+                .copied()
+                .collect();
+
+            assert_eq!(expected, articulation_points(&g));
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::algorithms::connectivity::articulation_points::articulation_points;
+use crate::algorithms::connectivity::bridges::bridges;
+use crate::algorithms::connectivity::low_link::low_link;
+use crate::graph::{
+    graph::{Graph, Path},
+    undirected_graph::{UndirectedGraph, Vertex, Weight},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum BlockCutNode {
+    Block(usize),
+    Cut(Vertex),
+}
+
+/// Block-cut tree: the vertex i of `tree` is `nodes[i]`, blocks first, and a
+/// block is adjacent to the cut vertices it contains. Blocks without cut
+/// vertices have no edge, so they are only in `nodes`.
+pub struct BlockCutTree {
+    pub tree: UndirectedGraph<Vertex, Weight>,
+    pub nodes: Vec<BlockCutNode>,
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Vec<Path<Vertex, Weight>> {
+    biconnected_components(g)
+}
+
+/// Edges of every block (maximal biconnected subgraph), each sorted, blocks
+/// in the order of their smallest edge. A tree edge (p, v) starts a new block
+/// when the subtree of v reaches no higher than p; otherwise it is in the
+/// block of the edge above p. A back edge is in the block of the tree edge
+/// into its lower end.
+pub fn biconnected_components(g: &dyn Graph<Vertex, Weight>) -> Vec<Path<Vertex, Weight>> {
+    let ll = low_link(g);
+    let mut block_above: HashMap<Vertex, usize> = HashMap::new();
+    let mut blocks: Vec<Path<Vertex, Weight>> = vec![];
+
+    for v in &ll.preorder {
+        let Some(p) = ll.parent.get(v) else {
+            continue;
+        };
+        let block = match block_above.get(p) {
+            Some(block) if ll.low[v] < ll.discovery[p] => *block,
+            _ => {
+                blocks.push(vec![]);
+                blocks.len() - 1
+            }
+        };
+        block_above.insert(*v, block);
+    }
+
+    for (u, v, w) in g.get_edges() {
+        if u != v {
+            let lower = if ll.discovery[u] > ll.discovery[v] {
+                u
+            } else {
+                v
+            };
+            blocks[block_above[lower]].push((*u, *v, *w));
+        }
+    }
+
+    for block in blocks.iter_mut() {
+        block.sort();
+    }
+    blocks.sort();
+
+    blocks
+}
+
+/// Label of every vertex, two vertices sharing one when they stay connected
+/// after removing any single edge. Labels are numbered from 0 in the order
+/// of their smallest vertex.
+pub fn two_edge_connected_components(g: &dyn Graph<Vertex, Weight>) -> HashMap<Vertex, usize> {
+    let bridges: BTreeSet<(Vertex, Vertex)> = bridges(g).iter().map(|(u, v, _)| (*u, *v)).collect();
+    let mut labels: HashMap<Vertex, usize> = HashMap::new();
+    let mut count = 0;
+
+    let mut vertices: Vec<&Vertex> = g.get_vertices().iter().collect();
+    vertices.sort();
+
+    for root in vertices {
+        if labels.contains_key(root) {
+            continue;
+        }
+
+        let label = count;
+        count += 1;
+        labels.insert(*root, label);
+        let mut stack = vec![*root];
+        while let Some(u) = stack.pop() {
+            for v in g._get_adj_list(&u).unwrap().keys() {
+                if !labels.contains_key(v) && !bridges.contains(&(u.min(*v), u.max(*v))) {
+                    labels.insert(*v, label);
+                    stack.push(*v);
+                }
+            }
+        }
+    }
+
+    labels
+}
+
+pub fn block_cut_tree(g: &dyn Graph<Vertex, Weight>) -> BlockCutTree {
+    let blocks = biconnected_components(g);
+    let mut cuts: Vec<Vertex> = articulation_points(g).into_iter().collect();
+    cuts.sort();
+
+    let mut nodes: Vec<BlockCutNode> = (0..blocks.len()).map(BlockCutNode::Block).collect();
+    let cut_node: HashMap<Vertex, Vertex> = cuts
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (*v, blocks.len() + i))
+        .collect();
+    nodes.extend(cuts.iter().map(|v| BlockCutNode::Cut(*v)));
+
+    let mut tree = UndirectedGraph::new();
+    for (i, block) in blocks.iter().enumerate() {
+        let vertices: BTreeSet<Vertex> = block.iter().flat_map(|(u, v, _)| [*u, *v]).collect();
+        for v in vertices {
+            if let Some(c) = cut_node.get(&v) {
+                tree.add_edge(i, *c, 1);
+            }
+        }
+    }
+
+    BlockCutTree { tree, nodes }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::cycles::is_acyclic::is_acyclic;
+    use crate::graph::generators::{rng::Rng, testing::random_edges};
+
+    fn two_triangles_and_a_tail() -> UndirectedGraph<Vertex, Weight> {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 3, 1);
+        g.add_edge(5, 6, 1);
+        g.add_edge(7, 8, 1);
+
+        g
+    }
+
+    #[test]
+    fn blocks() {
+        let g = two_triangles_and_a_tail();
+
+        let expected = vec![
+            vec![(1, 2, 1), (1, 3, 1), (2, 3, 1)],
+            vec![(3, 4, 1), (3, 5, 1), (4, 5, 1)],
+            vec![(5, 6, 1)],
+            vec![(7, 8, 1)],
+        ];
+        assert_eq!(expected, biconnected_components(&g));
+    }
+
+    #[test]
+    fn two_edge_connected() {
+        let g = two_triangles_and_a_tail();
+
+        // 3 is a cut vertex but no single edge separates the triangles
+        let expected = HashMap::from([
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (4, 0),
+            (5, 0),
+            (6, 1),
+            (7, 2),
+            (8, 3),
+        ]);
+        assert_eq!(expected, two_edge_connected_components(&g));
+    }
+
+    #[test]
+    fn tree() {
+        let g = two_triangles_and_a_tail();
+        let bc = block_cut_tree(&g);
+
+        assert_eq!(
+            vec![
+                BlockCutNode::Block(0),
+                BlockCutNode::Block(1),
+                BlockCutNode::Block(2),
+                BlockCutNode::Block(3),
+                BlockCutNode::Cut(3),
+                BlockCutNode::Cut(5),
+            ],
+            bc.nodes
+        );
+        let mut edges: Vec<_> = bc.tree.get_edges().iter().copied().collect();
+        edges.sort();
+        assert_eq!(vec![(0, 4, 1), (1, 4, 1), (1, 5, 1), (2, 5, 1)], edges);
+        assert!(is_acyclic(&bc.tree));
+    }
+
+    #[test]
+    fn every_edge_in_one_block() {
+        let mut rng = Rng::new(17);
+
+        for _ in 0..100 {
+            let mut g = UndirectedGraph::new();
+            random_edges(&mut g, 12, 18, &(1..=1), &mut rng);
+
+            let blocks = biconnected_components(&g);
+            let mut edges: Vec<_> = blocks.iter().flatten().copied().collect();
+            edges.sort();
+            let mut expected: Vec<_> = g.get_edges().iter().copied().collect();
+            expected.sort();
+            assert_eq!(expected, edges);
+
+            // Blocks share at most one vertex, and only cut vertices
+            let cuts = articulation_points(&g);
+            let vertex_sets: Vec<BTreeSet<Vertex>> = blocks
+                .iter()
+                .map(|b| b.iter().flat_map(|(u, v, _)| [*u, *v]).collect())
+                .collect();
+            for i in 0..vertex_sets.len() {
+                for j in i + 1..vertex_sets.len() {
+                    let shared: Vec<_> = vertex_sets[i].intersection(&vertex_sets[j]).collect();
+                    assert!(shared.len() <= 1);
+                    assert!(shared.iter().all(|v| cuts.contains(v)));
+                }
+            }
+            assert!(is_acyclic(&block_cut_tree(&g).tree));
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use crate::algorithms::connectivity::low_link::low_link;
+use crate::graph::{
+    graph::{Edge, Graph},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Vec<Edge<Vertex, Weight>> {
+    bridges(g)
+}
+
+/// Edges whose removal disconnects their endpoints, sorted. A tree edge is a
+/// bridge when no back edge leaves the subtree below it.
+pub fn bridges(g: &dyn Graph<Vertex, Weight>) -> Vec<Edge<Vertex, Weight>> {
+    let ll = low_link(g);
+
+    let mut bridges: Vec<Edge<Vertex, Weight>> = ll
+        .parent
+        .iter()
+        .filter(|(v, p)| ll.low[*v] > ll.discovery[*p])
+        .map(|(v, p)| (*p.min(v), *p.max(v), *g.get_weight(p, v).unwrap()))
+        .collect();
+    bridges.sort();
+
+    bridges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    #[test]
+    fn bridges_between_cycles() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 1, 3);
+        g.add_edge(3, 4, 4);
+        g.add_edge(4, 5, 5);
+        g.add_edge(5, 6, 6);
+        g.add_edge(6, 7, 7);
+        g.add_edge(7, 5, 8);
+        g.add_edge(8, 9, 9);
+
+        assert_eq!(vec![(3, 4, 4), (4, 5, 5), (8, 9, 9)], bridges(&g));
+    }
+
+    #[test]
+    fn no_bridges_in_cycle() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 1, 1);
+
+        assert!(bridges(&g).is_empty());
+    }
+
+    #[test]
+    fn every_tree_edge_is_a_bridge() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(2, 1, -1);
+        g.add_edge(1, 3, 2);
+        g.add_edge(3, 4, 3);
+
+        assert_eq!(vec![(1, 2, -1), (1, 3, 2), (3, 4, 3)], bridges(&g));
+    }
+
+    #[test]
+    fn long_path() {
+        let mut g = UndirectedGraph::new();
+
+        for v in 1..100_000 {
+            g.add_edge(v, v + 1, 1);
+        }
+        g.add_edge(100_000, 50_000, 1);
+
+        assert_eq!(49_999, bridges(&g).len());
+    }
+}
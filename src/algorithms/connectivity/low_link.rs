@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+/// DFS forest of an undirected graph with Tarjan's low-links: `low[v]` is the
+/// smallest discovery time reachable from the subtree of `v` through at most
+/// one back edge. Roots have no parent.
+#[derive(Debug)]
+pub struct LowLink {
+    pub discovery: HashMap<Vertex, usize>,
+    pub low: HashMap<Vertex, usize>,
+    pub parent: HashMap<Vertex, Vertex>,
+    pub preorder: Vec<Vertex>,
+}
+
+/// Iterative, so deep DFS trees do not overflow the stack. Vertices and
+/// neighbours are visited in increasing order.
+pub fn low_link(g: &dyn Graph<Vertex, Weight>) -> LowLink {
+    if g.is_directed() {
+        panic!("Low-links are defined on undirected graphs");
+    }
+
+    let mut ll = LowLink {
+        discovery: HashMap::new(),
+        low: HashMap::new(),
+        parent: HashMap::new(),
+        preorder: vec![],
+    };
+    let mut roots: Vec<&Vertex> = g.get_vertices().iter().collect();
+    roots.sort();
+
+    for root in roots {
+        if ll.discovery.contains_key(root) {
+            continue;
+        }
+
+        let mut stack = vec![(*root, neighbours(g, root), 0)];
+        visit(&mut ll, *root);
+
+        while let Some((u, adj, i)) = stack.last_mut() {
+            let u = *u;
+            match adj.get(*i) {
+                Some(&v) => {
+                    *i += 1;
+                    match ll.discovery.get(&v) {
+                        None => {
+                            ll.parent.insert(v, u);
+                            visit(&mut ll, v);
+                            stack.push((v, neighbours(g, &v), 0));
+                        }
+                        Some(&d) if ll.parent.get(&u) != Some(&v) => {
+                            let low = ll.low.get_mut(&u).unwrap();
+                            *low = (*low).min(d);
+                        }
+                        Some(_) => {}
+                    }
+                }
+                None => {
+                    stack.pop();
+                    if let Some(p) = ll.parent.get(&u) {
+                        let low = ll.low[&u];
+                        let parent_low = ll.low.get_mut(p).unwrap();
+                        *parent_low = (*parent_low).min(low);
+                    }
+                }
+            }
+        }
+    }
+
+    ll
+}
+
+fn visit(ll: &mut LowLink, v: Vertex) {
+    let time = ll.preorder.len();
+    ll.discovery.insert(v, time);
+    ll.low.insert(v, time);
+    ll.preorder.push(v);
+}
+
+fn neighbours(g: &dyn Graph<Vertex, Weight>, v: &Vertex) -> Vec<Vertex> {
+    let mut adj: Vec<Vertex> = g._get_adj_list(v).unwrap().keys().copied().collect();
+    adj.sort();
+    adj
+}
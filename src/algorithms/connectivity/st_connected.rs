@@ -0,0 +1,128 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::graph::{
+    graph::{Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> Option<Path<Vertex, Weight>> {
+    st_connected(g, s, t)
+}
+
+/// BFS from `s`: returns the path to `t` with the fewest edges, if any.
+pub fn st_connected(
+    g: &dyn Graph<Vertex, Weight>,
+    s: &Vertex,
+    t: &Vertex,
+) -> Option<Path<Vertex, Weight>> {
+    if !g.get_vertices().contains(s) {
+        return None;
+    }
+
+    let mut parents: HashMap<Vertex, (Vertex, Weight)> = HashMap::new();
+    let mut queue = VecDeque::from([*s]);
+    parents.insert(*s, (*s, 0));
+
+    while let Some(u) = queue.pop_front() {
+        if u == *t {
+            return Some(build_path(&parents, s, t));
+        }
+
+        for (v, w) in g._get_adj_list(&u).unwrap() {
+            if !parents.contains_key(v) {
+                parents.insert(*v, (u, *w));
+                queue.push_back(*v);
+            }
+        }
+    }
+
+    None
+}
+
+fn build_path(
+    parents: &HashMap<Vertex, (Vertex, Weight)>,
+    s: &Vertex,
+    t: &Vertex,
+) -> Path<Vertex, Weight> {
+    let mut path = vec![];
+    let mut v = *t;
+
+    while v != *s {
+        let (u, w) = parents[&v];
+        path.push((u, v, w));
+        v = u;
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    #[test]
+    fn st_connected_simple_graph() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 4993);
+        g.add_edge(2, 3, 1392);
+        g.add_edge(3, 4, 8856);
+        g.add_edge(4, 5, -433);
+
+        let expected = Some(vec![(1, 2, 4993), (2, 3, 1392), (3, 4, 8856), (4, 5, -433)]);
+        let current = st_connected(&g, &1, &5);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn st_connected_fewest_edges() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(1, 6, 100);
+        g.add_edge(6, 5, 100);
+
+        let expected = Some(vec![(1, 6, 100), (6, 5, 100)]);
+        let current = st_connected(&g, &1, &5);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn st_connected_same_vertex() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 1);
+
+        let expected = Some(vec![]);
+        let current = st_connected(&g, &2, &2);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn st_connected_different_components() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(4, 5, 1);
+
+        let expected = None;
+        let current = st_connected(&g, &1, &5);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn st_connected_non_existent_vertex() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 1);
+
+        assert_eq!(None, st_connected(&g, &7, &7));
+        assert_eq!(None, st_connected(&g, &1, &7));
+    }
+}
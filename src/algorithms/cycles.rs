@@ -1 +1,2 @@
 pub mod is_acyclic;
+pub mod simple_cycles;
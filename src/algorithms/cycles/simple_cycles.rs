@@ -0,0 +1,775 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::algorithms::connected_components::strongly_connected_components::tarjan_components;
+use crate::graph::{
+    graph::{Edge, Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub type Cycles = Box<dyn Iterator<Item = Path<Vertex, Weight>>>;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CycleLimits {
+    pub max_length: Option<usize>,
+    pub max_count: Option<usize>,
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, limits: CycleLimits) -> Cycles {
+    simple_cycles(g, limits)
+}
+
+/// Lazily enumerates every simple cycle of `g`, each one as the sequence of
+/// its edges. Directed graphs use Johnson's algorithm, undirected graphs the
+/// combinations of a fundamental cycle basis, or a depth-bounded search when
+/// the length is bounded. Undirected graphs whose cycle basis has 128 or more
+/// cycles need a bound on the length or the count.
+pub fn simple_cycles(g: &dyn Graph<Vertex, Weight>, limits: CycleLimits) -> Cycles {
+    let cycles: Cycles = match (g.is_directed(), limits.max_length) {
+        (true, max_length) => Box::new(JohnsonCycles::new(g, max_length)),
+        (false, Some(max_length)) => Box::new(BoundedCycles::new(g, max_length)),
+        (false, None) => {
+            let cycles = CycleBasisCycles::new(g);
+            if cycles.end.is_none() && limits.max_count.is_none() {
+                panic!("Too many independent cycles to list them all, bound their length or count");
+            }
+            Box::new(cycles)
+        }
+    };
+
+    match limits.max_count {
+        Some(count) => Box::new(cycles.take(count)),
+        None => cycles,
+    }
+}
+
+pub struct JohnsonCycles {
+    adj: HashMap<Vertex, HashMap<Vertex, Weight>>,
+    max_length: Option<usize>,
+    self_loops: Vec<Path<Vertex, Weight>>,
+    components: Vec<HashSet<Vertex>>,
+    search: Option<JohnsonSearch>,
+}
+
+struct JohnsonSearch {
+    component: HashSet<Vertex>,
+    start: Vertex,
+    path: Vec<Vertex>,
+    blocked: HashSet<Vertex>,
+    closed: HashSet<Vertex>,
+    b: HashMap<Vertex, HashSet<Vertex>>,
+    stack: Vec<(Vertex, Vec<Vertex>)>,
+}
+
+impl JohnsonCycles {
+    pub fn new(g: &dyn Graph<Vertex, Weight>, max_length: Option<usize>) -> JohnsonCycles {
+        let mut adj: HashMap<Vertex, HashMap<Vertex, Weight>> = g
+            .get_vertices()
+            .iter()
+            .map(|u| (*u, g._get_adj_list(u).unwrap().clone()))
+            .collect();
+
+        let mut self_loops = vec![];
+        for (u, adj_list) in adj.iter_mut() {
+            if let Some(w) = adj_list.remove(u) {
+                self_loops.push(vec![(*u, *u, w)]);
+            }
+        }
+        if max_length == Some(0) {
+            self_loops.clear();
+        }
+        self_loops.sort_by(|a, b| b.cmp(a));
+
+        let vertices = adj.keys().cloned().collect();
+        let components = strongly_connected_components(&adj, &vertices)
+            .into_iter()
+            .filter(|c| c.len() > 1)
+            .collect();
+
+        JohnsonCycles {
+            adj,
+            max_length,
+            self_loops,
+            components,
+            search: None,
+        }
+    }
+
+    fn neighbours(&self, component: &HashSet<Vertex>, u: &Vertex) -> Vec<Vertex> {
+        let mut neighbours: Vec<Vertex> = self.adj[u]
+            .keys()
+            .filter(|v| component.contains(v))
+            .cloned()
+            .collect();
+        neighbours.sort_by(|a, b| b.cmp(a));
+
+        neighbours
+    }
+
+    fn start_search(&mut self) -> bool {
+        let component = match self.components.pop() {
+            Some(component) => component,
+            None => return false,
+        };
+        let start = *component.iter().min().unwrap();
+        let neighbours = self.neighbours(&component, &start);
+
+        self.search = Some(JohnsonSearch {
+            component,
+            start,
+            path: vec![start],
+            blocked: HashSet::from([start]),
+            closed: HashSet::new(),
+            b: HashMap::new(),
+            stack: vec![(start, neighbours)],
+        });
+
+        true
+    }
+
+    fn finish_search(&mut self) {
+        let mut search = self.search.take().unwrap();
+        search.component.remove(&search.start);
+        self.adj.remove(&search.start);
+
+        self.components.extend(
+            strongly_connected_components(&self.adj, &search.component)
+                .into_iter()
+                .filter(|c| c.len() > 1),
+        );
+    }
+
+    fn build_cycle(&self, path: &[Vertex]) -> Path<Vertex, Weight> {
+        (0..path.len())
+            .map(|i| {
+                let (u, v) = (path[i], path[(i + 1) % path.len()]);
+                (u, v, self.adj[&u][&v])
+            })
+            .collect()
+    }
+}
+
+impl Iterator for JohnsonCycles {
+    type Item = Path<Vertex, Weight>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(cycle) = self.self_loops.pop() {
+            return Some(cycle);
+        }
+
+        loop {
+            if self.search.is_none() && !self.start_search() {
+                return None;
+            }
+
+            let mut search = self.search.take().unwrap();
+            let mut cycle = None;
+
+            while let Some((u, neighbours)) = search.stack.last_mut() {
+                let u = *u;
+                if let Some(v) = neighbours.pop() {
+                    if v == search.start {
+                        cycle = Some(search.path.clone());
+                        search.closed.extend(search.path.iter());
+                    } else if !search.blocked.contains(&v) {
+                        if self.max_length.is_none_or(|l| search.path.len() < l) {
+                            let neighbours = self.neighbours(&search.component, &v);
+                            search.path.push(v);
+                            search.stack.push((v, neighbours));
+                            search.closed.remove(&v);
+                            search.blocked.insert(v);
+                        } else {
+                            search.closed.extend(search.path.iter());
+                        }
+                    }
+                } else {
+                    if search.closed.contains(&u) {
+                        unblock(&u, &mut search.blocked, &mut search.b);
+                    } else {
+                        for v in self.adj[&u].keys() {
+                            if search.component.contains(v) {
+                                search.b.entry(*v).or_default().insert(u);
+                            }
+                        }
+                    }
+                    search.stack.pop();
+                    search.path.pop();
+                }
+
+                if cycle.is_some() {
+                    break;
+                }
+            }
+
+            let finished = search.stack.is_empty();
+            self.search = Some(search);
+
+            if let Some(path) = cycle {
+                return Some(self.build_cycle(&path));
+            }
+            if finished {
+                self.finish_search();
+            }
+        }
+    }
+}
+
+fn unblock(u: &Vertex, blocked: &mut HashSet<Vertex>, b: &mut HashMap<Vertex, HashSet<Vertex>>) {
+    let mut stack = vec![*u];
+
+    while let Some(v) = stack.pop() {
+        if blocked.remove(&v) {
+            if let Some(bv) = b.get_mut(&v) {
+                stack.extend(bv.drain());
+            }
+        }
+    }
+}
+
+fn strongly_connected_components(
+    adj: &HashMap<Vertex, HashMap<Vertex, Weight>>,
+    vertices: &HashSet<Vertex>,
+) -> Vec<HashSet<Vertex>> {
+    let mut roots: Vec<Vertex> = vertices.iter().copied().collect();
+    roots.sort();
+
+    tarjan_components(&roots, |u| {
+        adj[u]
+            .keys()
+            .filter(|v| vertices.contains(v))
+            .copied()
+            .collect()
+    })
+    .into_iter()
+    .map(|c| c.into_iter().collect())
+    .collect()
+}
+
+/// Fundamental cycles of a spanning forest of an undirected graph, one for
+/// every non-tree edge. Self-loops are left out.
+pub fn cycle_basis(g: &dyn Graph<Vertex, Weight>) -> Vec<Path<Vertex, Weight>> {
+    let mut parents: HashMap<Vertex, (Vertex, Weight)> = HashMap::new();
+    let mut depths: HashMap<Vertex, usize> = HashMap::new();
+    let mut tree_edges: HashSet<(Vertex, Vertex)> = HashSet::new();
+
+    let mut roots: Vec<&Vertex> = g.get_vertices().iter().collect();
+    roots.sort();
+
+    for root in roots {
+        if depths.contains_key(root) {
+            continue;
+        }
+
+        depths.insert(*root, 0);
+        let mut stack = vec![*root];
+        while let Some(u) = stack.pop() {
+            let mut adj_list: Vec<(&Vertex, &Weight)> =
+                g._get_adj_list(&u).unwrap().iter().collect();
+            adj_list.sort();
+
+            for (v, w) in adj_list {
+                if !depths.contains_key(v) {
+                    depths.insert(*v, depths[&u] + 1);
+                    parents.insert(*v, (u, *w));
+                    tree_edges.insert((u.min(*v), u.max(*v)));
+                    stack.push(*v);
+                }
+            }
+        }
+    }
+
+    let mut edges: Vec<&Edge<Vertex, Weight>> = g.get_edges().iter().collect();
+    edges.sort();
+
+    edges
+        .into_iter()
+        .filter(|(u, v, _)| u != v && !tree_edges.contains(&(*u, *v)))
+        .map(|(u, v, w)| {
+            let (mut a, mut b) = (*u, *v);
+            let mut from_u = vec![];
+            let mut from_v = vec![];
+
+            while a != b {
+                if depths[&a] >= depths[&b] {
+                    let (p, pw) = parents[&a];
+                    from_u.push((a, p, pw));
+                    a = p;
+                } else {
+                    let (p, pw) = parents[&b];
+                    from_v.push((p, b, pw));
+                    b = p;
+                }
+            }
+
+            let mut cycle = vec![(*v, *u, *w)];
+            cycle.extend(from_u);
+            cycle.extend(from_v.into_iter().rev());
+
+            cycle
+        })
+        .collect()
+}
+
+pub struct CycleBasisCycles {
+    edges: Vec<Edge<Vertex, Weight>>,
+    basis: Vec<Vec<bool>>,
+    current: Vec<bool>,
+    self_loops: Vec<Path<Vertex, Weight>>,
+    step: u128,
+    // 2^(basis size), None once it does not fit
+    end: Option<u128>,
+}
+
+impl CycleBasisCycles {
+    pub fn new(g: &dyn Graph<Vertex, Weight>) -> CycleBasisCycles {
+        let mut edges: Vec<Edge<Vertex, Weight>> = g.get_edges().iter().cloned().collect();
+        edges.sort();
+
+        let mut self_loops: Vec<Path<Vertex, Weight>> = edges
+            .iter()
+            .filter(|(u, v, _)| u == v)
+            .map(|e| vec![*e])
+            .collect();
+        self_loops.reverse();
+
+        let indexes: HashMap<(Vertex, Vertex), usize> = edges
+            .iter()
+            .enumerate()
+            .map(|(i, (u, v, _))| ((*u, *v), i))
+            .collect();
+
+        let basis: Vec<Vec<bool>> = cycle_basis(g)
+            .iter()
+            .map(|cycle| {
+                let mut set = vec![false; edges.len()];
+                for (u, v, _) in cycle {
+                    set[indexes[&(*u.min(v), *u.max(v))]] = true;
+                }
+                set
+            })
+            .collect();
+        let end = 1u128.checked_shl(basis.len() as u32);
+
+        CycleBasisCycles {
+            current: vec![false; edges.len()],
+            edges,
+            basis,
+            self_loops,
+            step: 0,
+            end,
+        }
+    }
+
+    fn as_simple_cycle(&self) -> Option<Path<Vertex, Weight>> {
+        let mut adj: HashMap<Vertex, Vec<(Vertex, Weight)>> = HashMap::new();
+        let mut size = 0;
+        for (i, _) in self.current.iter().enumerate().filter(|(_, b)| **b) {
+            let (u, v, w) = self.edges[i];
+            adj.entry(u).or_default().push((v, w));
+            adj.entry(v).or_default().push((u, w));
+            size += 1;
+        }
+
+        if adj.values().any(|adj_list| adj_list.len() != 2) {
+            return None;
+        }
+
+        let start = *adj.keys().min().unwrap();
+        let mut cycle = vec![];
+        let (mut prev, mut u) = (start, start);
+        loop {
+            let (v, w) = *adj[&u].iter().filter(|(v, _)| *v != prev).min().unwrap();
+            cycle.push((u, v, w));
+            (prev, u) = (u, v);
+
+            if u == start {
+                break;
+            }
+        }
+
+        if cycle.len() == size {
+            Some(cycle)
+        } else {
+            None
+        }
+    }
+}
+
+impl Iterator for CycleBasisCycles {
+    type Item = Path<Vertex, Weight>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(cycle) = self.self_loops.pop() {
+            return Some(cycle);
+        }
+
+        loop {
+            self.step += 1;
+            if self.end.is_some_and(|end| self.step >= end) {
+                return None;
+            }
+
+            let flipped = &self.basis[self.step.trailing_zeros() as usize];
+            for (c, f) in self.current.iter_mut().zip(flipped) {
+                *c ^= *f;
+            }
+
+            if let Some(cycle) = self.as_simple_cycle() {
+                return Some(cycle);
+            }
+        }
+    }
+}
+
+/// Cycles of at most `max_length` edges of an undirected graph. From each
+/// vertex in increasing order, a DFS of depth at most `max_length` walks
+/// larger vertices only and closes a cycle when it gets back; of the two
+/// orientations only the one leaving towards the smaller neighbour is kept,
+/// as `CycleBasisCycles` does.
+pub struct BoundedCycles {
+    adj: HashMap<Vertex, Vec<(Vertex, Weight)>>,
+    max_length: usize,
+    self_loops: Vec<Path<Vertex, Weight>>,
+    starts: Vec<Vertex>,
+    path: Vec<(Vertex, Weight)>,
+    stack: Vec<usize>,
+}
+
+impl BoundedCycles {
+    pub fn new(g: &dyn Graph<Vertex, Weight>, max_length: usize) -> BoundedCycles {
+        let mut self_loops = vec![];
+        let adj = g
+            .get_vertices()
+            .iter()
+            .map(|u| {
+                let mut adj_list: Vec<(Vertex, Weight)> = vec![];
+                for (v, w) in g._get_adj_list(u).unwrap() {
+                    if v == u {
+                        self_loops.push(vec![(*u, *u, *w)]);
+                    } else {
+                        adj_list.push((*v, *w));
+                    }
+                }
+                adj_list.sort();
+                (*u, adj_list)
+            })
+            .collect();
+        if max_length == 0 {
+            self_loops.clear();
+        }
+        self_loops.sort_by(|a, b| b.cmp(a));
+
+        let mut starts: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+        starts.sort_by(|a, b| b.cmp(a));
+
+        BoundedCycles {
+            adj,
+            max_length,
+            self_loops,
+            starts,
+            path: vec![],
+            stack: vec![],
+        }
+    }
+}
+
+impl Iterator for BoundedCycles {
+    type Item = Path<Vertex, Weight>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(cycle) = self.self_loops.pop() {
+            return Some(cycle);
+        }
+
+        loop {
+            if self.stack.is_empty() {
+                let start = self.starts.pop()?;
+                self.path = vec![(start, 0)];
+                self.stack = vec![0];
+            }
+
+            let start = self.path[0].0;
+            let u = self.path[self.path.len() - 1].0;
+            let i = self.stack.last_mut().unwrap();
+            let Some(&(v, w)) = self.adj[&u].get(*i) else {
+                self.stack.pop();
+                self.path.pop();
+                continue;
+            };
+            *i += 1;
+
+            if v == start {
+                if self.path.len() >= 3 && self.path[1].0 < u {
+                    let mut cycle: Path<Vertex, Weight> = self
+                        .path
+                        .windows(2)
+                        .map(|e| (e[0].0, e[1].0, e[1].1))
+                        .collect();
+                    cycle.push((u, v, w));
+                    return Some(cycle);
+                }
+            } else if v > start
+                && self.path.len() < self.max_length
+                && self.path.iter().all(|(x, _)| *x != v)
+            {
+                self.path.push((v, w));
+                self.stack.push(0);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+    fn sorted(cycles: Cycles) -> Vec<Path<Vertex, Weight>> {
+        let mut cycles: Vec<Path<Vertex, Weight>> = cycles.collect();
+        cycles.sort();
+        cycles
+    }
+
+    #[test]
+    fn simple_cycles_directed_graph() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        g.add_edge(2, 0, 3);
+        g.add_edge(1, 0, 4);
+        g.add_edge(2, 3, 5);
+
+        let expected = vec![
+            vec![(0, 1, 1), (1, 0, 4)],
+            vec![(0, 1, 1), (1, 2, 2), (2, 0, 3)],
+        ];
+        let current = sorted(simple_cycles(&g, CycleLimits::default()));
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn simple_cycles_directed_complete_graph() {
+        let mut g = DirectedGraph::new();
+
+        for u in 0..4 {
+            for v in 0..4 {
+                if u != v {
+                    g.add_edge(u, v, 1);
+                }
+            }
+        }
+
+        // 6 two-cycles, 8 three-cycles and 6 four-cycles
+        let cycles = sorted(simple_cycles(&g, CycleLimits::default()));
+        assert_eq!(20, cycles.len());
+        assert_eq!(6, cycles.iter().filter(|c| c.len() == 4).count());
+    }
+
+    #[test]
+    fn simple_cycles_directed_self_loop() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(0, 0, 7);
+        g.add_edge(0, 1, 1);
+
+        let expected = vec![vec![(0, 0, 7)]];
+        let current = sorted(simple_cycles(&g, CycleLimits::default()));
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn simple_cycles_directed_max_length() {
+        let mut g = DirectedGraph::new();
+
+        for u in 0..4 {
+            for v in 0..4 {
+                if u != v {
+                    g.add_edge(u, v, 1);
+                }
+            }
+        }
+
+        let limits = CycleLimits {
+            max_length: Some(3),
+            max_count: None,
+        };
+        let cycles = sorted(simple_cycles(&g, limits));
+        assert_eq!(14, cycles.len());
+        assert!(cycles.iter().all(|c| c.len() <= 3));
+    }
+
+    #[test]
+    fn simple_cycles_undirected_graph() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(0, 1, 1);
+        g.add_edge(1, 2, 2);
+        g.add_edge(2, 0, 3);
+        g.add_edge(2, 3, 4);
+        g.add_edge(3, 0, 5);
+        g.add_edge(3, 4, 6);
+
+        let expected = vec![
+            vec![(0, 1, 1), (1, 2, 2), (2, 0, 3)],
+            vec![(0, 1, 1), (1, 2, 2), (2, 3, 4), (3, 0, 5)],
+            vec![(0, 2, 3), (2, 3, 4), (3, 0, 5)],
+        ];
+        let current = sorted(simple_cycles(&g, CycleLimits::default()));
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn simple_cycles_undirected_complete_graph() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..5 {
+            for v in u + 1..5 {
+                g.add_edge(u, v, 1);
+            }
+        }
+
+        // 10 triangles, 15 squares and 12 pentagons
+        let cycles = sorted(simple_cycles(&g, CycleLimits::default()));
+        assert_eq!(37, cycles.len());
+    }
+
+    #[test]
+    fn simple_cycles_undirected_limits() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..5 {
+            for v in u + 1..5 {
+                g.add_edge(u, v, 1);
+            }
+        }
+
+        let limits = CycleLimits {
+            max_length: Some(3),
+            max_count: None,
+        };
+        assert_eq!(10, simple_cycles(&g, limits).count());
+
+        let limits = CycleLimits {
+            max_length: None,
+            max_count: Some(4),
+        };
+        assert_eq!(4, simple_cycles(&g, limits).count());
+    }
+
+    #[test]
+    fn simple_cycles_undirected_dense_graph_bounded() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..30 {
+            for v in u + 1..30 {
+                g.add_edge(u, v, 1);
+            }
+        }
+        g.add_edge(3, 3, 2);
+
+        // C(30, 3) triangles and the self-loop, without walking the 2^406
+        // subsets of the cycle basis
+        let limits = CycleLimits {
+            max_length: Some(3),
+            max_count: None,
+        };
+        let cycles = sorted(simple_cycles(&g, limits));
+        assert_eq!(4060 + 1, cycles.len());
+        assert!(cycles.contains(&vec![(0, 1, 1), (1, 2, 1), (2, 0, 1)]));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Too many independent cycles to list them all, bound their length or count"
+    )]
+    fn simple_cycles_undirected_large_cycle_space() {
+        let mut g = UndirectedGraph::new();
+
+        // 136 independent cycles
+        for u in 0..18 {
+            for v in u + 1..18 {
+                g.add_edge(u, v, 1);
+            }
+        }
+
+        simple_cycles(&g, CycleLimits::default()).count();
+    }
+
+    #[test]
+    fn simple_cycles_undirected_large_cycle_space_max_count() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..18 {
+            for v in u + 1..18 {
+                g.add_edge(u, v, 1);
+            }
+        }
+
+        let limits = CycleLimits {
+            max_length: None,
+            max_count: Some(10),
+        };
+        let cycles: Vec<Path<Vertex, Weight>> = simple_cycles(&g, limits).collect();
+        assert_eq!(10, cycles.len());
+        assert!(cycles.iter().all(|c| c
+            .iter()
+            .zip(c.iter().cycle().skip(1))
+            .all(|(e, f)| e.1 == f.0)));
+    }
+
+    #[test]
+    fn simple_cycles_undirected_bounded_matches_basis() {
+        let mut g = UndirectedGraph::new();
+
+        for (u, v) in [
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 0),
+            (1, 4),
+            (3, 5),
+            (5, 1),
+        ] {
+            g.add_edge(u, v, (u + v) as Weight);
+        }
+
+        for length in 0..=6 {
+            let limits = CycleLimits {
+                max_length: Some(length),
+                max_count: None,
+            };
+            let expected = sorted(Box::new(
+                CycleBasisCycles::new(&g).filter(move |c| c.len() <= length),
+            ));
+            assert_eq!(expected, sorted(simple_cycles(&g, limits)));
+        }
+    }
+
+    #[test]
+    fn simple_cycles_acyclic_graph() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(0, 1, 1);
+        g.add_edge(0, 2, 1);
+        g.add_edge(2, 3, 1);
+
+        assert_eq!(0, simple_cycles(&g, CycleLimits::default()).count());
+    }
+
+    #[test]
+    fn cycle_basis_size() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..5 {
+            for v in u + 1..5 {
+                g.add_edge(u, v, 1);
+            }
+        }
+        g.add_edge(7, 8, 1);
+
+        // m - n + c = 11 - 7 + 2
+        assert_eq!(6, cycle_basis(&g).len());
+    }
+}
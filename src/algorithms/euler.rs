@@ -0,0 +1,16 @@
+pub mod chinese_postman;
+pub mod hierholzer;
+
+use crate::graph::undirected_graph::Vertex;
+
+/// Why a graph has no Eulerian trail of the requested kind.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EulerError {
+    /// Undirected graphs: the vertices of odd degree, sorted.
+    OddDegree(Vec<Vertex>),
+    /// Directed graphs: out-degree minus in-degree of the vertices where the
+    /// two differ, sorted by vertex.
+    Unbalanced(Vec<(Vertex, isize)>),
+    /// The vertices of every component with edges, when there are several.
+    Disconnected(Vec<Vec<Vertex>>),
+}
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use crate::algorithms::{
+    euler::hierholzer::circuit, matching::weighted_matching::min_weight_perfect_matching,
+    shortest_paths::dijkstra::dijkstra,
+};
+use crate::graph::{
+    graph::{Edge, Graph, Path},
+    undirected_graph::{UndirectedGraph, Vertex, Weight},
+};
+
+/// A closed walk through every edge, in the order walked, and its total
+/// weight.
+#[derive(Debug, PartialEq, Eq)]
+pub struct PostmanRoute {
+    pub circuit: Path<Vertex, Weight>,
+    pub cost: i64,
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Option<PostmanRoute> {
+    chinese_postman(g)
+}
+
+/// Shortest closed walk using every edge at least once, from the smallest
+/// vertex with edges. The odd-degree vertices are paired by a minimum-weight
+/// perfect matching over their shortest-path distances, the edges of those
+/// paths are walked twice, and the doubled graph is Eulerian. `None` when the
+/// edges lie in more than one component. Weights must be non-negative.
+pub fn chinese_postman(g: &dyn Graph<Vertex, Weight>) -> Option<PostmanRoute> {
+    if g.is_directed() {
+        panic!("Chinese postman needs an undirected graph");
+    }
+    let mut edges: Vec<Edge<Vertex, Weight>> = g.get_edges().iter().cloned().collect();
+    edges.sort();
+    if edges.iter().any(|e| e.2 < 0) {
+        panic!("Chinese postman needs non-negative weights");
+    }
+
+    let mut degrees: HashMap<Vertex, usize> = HashMap::new();
+    for (u, v, _) in &edges {
+        *degrees.entry(*u).or_default() += 1;
+        *degrees.entry(*v).or_default() += 1;
+    }
+    let mut odd: Vec<Vertex> = degrees
+        .into_iter()
+        .filter(|(_, d)| d % 2 == 1)
+        .map(|(v, _)| v)
+        .collect();
+    odd.sort();
+
+    let searches: HashMap<Vertex, _> = odd.iter().map(|v| (*v, dijkstra(g, v))).collect();
+
+    let mut distances = UndirectedGraph::new();
+    for (i, u) in odd.iter().enumerate() {
+        for v in &odd[i + 1..] {
+            match searches[u].0.get(v) {
+                Some(d) => distances.add_edge(*u, *v, *d),
+                None => return None,
+            }
+        }
+    }
+
+    for (u, v, _) in min_weight_perfect_matching(&distances).unwrap_or_default() {
+        let parents = &searches[&u].1;
+        let mut x = v;
+        while x != u {
+            let p = parents[&x];
+            edges.push((p, x, *g.get_weight(&p, &x).unwrap()));
+            x = p;
+        }
+    }
+
+    let circuit = circuit(&edges, false, None).ok()?;
+    let cost = circuit.iter().map(|e| e.2 as i64).sum();
+
+    Some(PostmanRoute { circuit, cost })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::shortest_paths::all_pairs::{floyd_warshall, AllPairs};
+    use crate::graph::generators::{random::random_connected, rng::Rng};
+
+    /// Cheapest pairing of `odd` by trying every partner of the first vertex.
+    fn best_pairing(odd: &[Vertex], distance: &impl Fn(Vertex, Vertex) -> i64) -> i64 {
+        if odd.is_empty() {
+            return 0;
+        }
+        (1..odd.len())
+            .map(|i| {
+                let rest: Vec<Vertex> = odd[1..]
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| j + 1 != i)
+                    .map(|(_, v)| *v)
+                    .collect();
+                distance(odd[0], odd[i]) + best_pairing(&rest, distance)
+            })
+            .min()
+            .unwrap()
+    }
+
+    fn closed_walk_of(g: &UndirectedGraph<Vertex, Weight>, route: &PostmanRoute) {
+        let circuit = &route.circuit;
+        assert!(circuit.windows(2).all(|w| w[0].1 == w[1].0));
+        assert_eq!(circuit[0].0, circuit[circuit.len() - 1].1);
+        for (u, v, w) in circuit {
+            assert_eq!(Some(w), g.get_weight(u, v));
+        }
+        for (u, v, _) in g.get_edges() {
+            assert!(circuit
+                .iter()
+                .any(|(a, b, _)| (a, b) == (u, v) || (a, b) == (v, u)));
+        }
+        assert_eq!(route.cost, circuit.iter().map(|e| e.2 as i64).sum::<i64>());
+    }
+
+    #[test]
+    fn eulerian_graph_is_walked_once() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 4);
+        g.add_edge(3, 1, 5);
+
+        let route = chinese_postman(&g).unwrap();
+        assert_eq!(12, route.cost);
+        assert_eq!(3, route.circuit.len());
+        closed_walk_of(&g, &route);
+    }
+
+    #[test]
+    fn path_is_walked_twice() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 4);
+
+        let route = chinese_postman(&g).unwrap();
+        assert_eq!(14, route.cost);
+        assert_eq!(4, route.circuit.len());
+        closed_walk_of(&g, &route);
+    }
+
+    #[test]
+    fn textbook_instance() {
+        // Odd vertices 2, 3, 4 and 5: pairing 2-3 (3) with 4-5 (2) beats
+        // 2-4 with 3-5 (12) and 2-5 with 3-4 (15)
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 4);
+        g.add_edge(1, 3, 2);
+        g.add_edge(2, 3, 3);
+        g.add_edge(2, 4, 5);
+        g.add_edge(3, 5, 7);
+        g.add_edge(4, 5, 2);
+        g.add_edge(4, 6, 3);
+        g.add_edge(5, 6, 6);
+
+        let route = chinese_postman(&g).unwrap();
+        assert_eq!(32 + 3 + 2, route.cost);
+        closed_walk_of(&g, &route);
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 4, 1);
+
+        assert_eq!(None, chinese_postman(&g));
+    }
+
+    #[test]
+    fn disconnected_even_components() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 6, 1);
+        g.add_edge(6, 4, 1);
+
+        assert_eq!(None, chinese_postman(&g));
+    }
+
+    #[test]
+    #[should_panic(expected = "Chinese postman needs non-negative weights")]
+    fn negative_weight() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, -1);
+
+        chinese_postman(&g);
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = Rng::new(50);
+
+        for _ in 0..100 {
+            let n = 2 + rng.below(8);
+            let m = (n - 1 + rng.below(2 * n)).min(n * (n - 1) / 2);
+            let g = random_connected(n, m, &(0..=19), &mut rng);
+
+            let m = match floyd_warshall(&g) {
+                AllPairs::Distances(m) => m,
+                AllPairs::NegativeCycle(_) => unreachable!(),
+            };
+            let mut odd: Vec<Vertex> = g
+                .get_vertices()
+                .iter()
+                .filter(|v| g._get_adj_list(v).unwrap().len() % 2 == 1)
+                .cloned()
+                .collect();
+            odd.sort();
+            let total: i64 = g.get_edges().iter().map(|e| e.2 as i64).sum();
+            let distance = |u, v| m.distance(&u, &v).unwrap() as i64;
+
+            let route = chinese_postman(&g).unwrap();
+            assert_eq!(total + best_pairing(&odd, &distance), route.cost);
+            closed_walk_of(&g, &route);
+        }
+    }
+}
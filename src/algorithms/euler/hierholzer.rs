@@ -0,0 +1,338 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::algorithms::euler::EulerError;
+use crate::data_structures::union_find::UnionFind;
+use crate::graph::{
+    graph::{Edge, Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Result<Path<Vertex, Weight>, EulerError> {
+    eulerian_path(g)
+}
+
+/// Closed trail through every edge, from the smallest vertex with edges.
+pub fn eulerian_circuit(g: &dyn Graph<Vertex, Weight>) -> Result<Path<Vertex, Weight>, EulerError> {
+    circuit(&sorted_edges(g), g.is_directed(), None)
+}
+
+/// Trail through every edge, closed when the degrees allow it and otherwise
+/// from the smallest odd vertex, or from the one with an extra outgoing arc.
+pub fn eulerian_path(g: &dyn Graph<Vertex, Weight>) -> Result<Path<Vertex, Weight>, EulerError> {
+    trail(&sorted_edges(g), g.is_directed())
+}
+
+/// Eulerian circuit of the multigraph given by `edges`, starting from `start`
+/// or else from the smallest vertex with edges. Each edge of the circuit is
+/// oriented the way it is walked.
+pub fn circuit(
+    edges: &[Edge<Vertex, Weight>],
+    directed: bool,
+    start: Option<&Vertex>,
+) -> Result<Path<Vertex, Weight>, EulerError> {
+    let unbalanced = unbalanced(edges, directed);
+    if !unbalanced.is_empty() {
+        return Err(degree_error(unbalanced, directed));
+    }
+
+    let start = match start {
+        Some(_) if edges.is_empty() => return Ok(vec![]),
+        Some(s) if edges.iter().any(|(u, v, _)| u == s || v == s) => *s,
+        Some(s) => panic!("Vertex {} has no edges", s),
+        None => match edges.iter().map(|(u, v, _)| *u.min(v)).min() {
+            Some(s) => s,
+            None => return Ok(vec![]),
+        },
+    };
+
+    hierholzer(edges, directed, start)
+}
+
+/// Eulerian trail of the multigraph given by `edges`: a circuit if there is
+/// one, otherwise a path between the two odd vertices, or from the vertex with
+/// one more outgoing arc than incoming to the one with one fewer.
+pub fn trail(
+    edges: &[Edge<Vertex, Weight>],
+    directed: bool,
+) -> Result<Path<Vertex, Weight>, EulerError> {
+    let unbalanced = unbalanced(edges, directed);
+
+    let start = match unbalanced.as_slice() {
+        [] => return circuit(edges, directed, None),
+        [(a, _), (_, _)] if !directed => *a,
+        [(a, 1), (_, -1)] | [(_, -1), (a, 1)] if directed => *a,
+        _ => return Err(degree_error(unbalanced, directed)),
+    };
+
+    hierholzer(edges, directed, start)
+}
+
+fn sorted_edges(g: &dyn Graph<Vertex, Weight>) -> Vec<Edge<Vertex, Weight>> {
+    let mut edges: Vec<Edge<Vertex, Weight>> = g.get_edges().iter().cloned().collect();
+    edges.sort();
+
+    edges
+}
+
+/// Out-degree minus in-degree, or the degree modulo 2 when undirected, of
+/// the vertices where it is not zero.
+fn unbalanced(edges: &[Edge<Vertex, Weight>], directed: bool) -> Vec<(Vertex, isize)> {
+    let mut balance: HashMap<Vertex, isize> = HashMap::new();
+    for (u, v, _) in edges {
+        *balance.entry(*u).or_default() += 1;
+        *balance.entry(*v).or_default() += if directed { -1 } else { 1 };
+    }
+
+    let mut unbalanced: Vec<(Vertex, isize)> = balance
+        .into_iter()
+        .map(|(v, b)| (v, if directed { b } else { b % 2 }))
+        .filter(|(_, b)| *b != 0)
+        .collect();
+    unbalanced.sort();
+
+    unbalanced
+}
+
+fn degree_error(unbalanced: Vec<(Vertex, isize)>, directed: bool) -> EulerError {
+    if directed {
+        EulerError::Unbalanced(unbalanced)
+    } else {
+        EulerError::OddDegree(unbalanced.into_iter().map(|(v, _)| v).collect())
+    }
+}
+
+/// Hierholzer's algorithm in O(n + m) once the degrees are known to allow
+/// the trail: walk unused edges until stuck, and splice in the detours found
+/// while backtracking. Leftover edges mean the graph is disconnected.
+fn hierholzer(
+    edges: &[Edge<Vertex, Weight>],
+    directed: bool,
+    start: Vertex,
+) -> Result<Path<Vertex, Weight>, EulerError> {
+    let mut incident: HashMap<Vertex, Vec<(Vertex, usize)>> = HashMap::new();
+    for (i, (u, v, _)) in edges.iter().enumerate() {
+        incident.entry(*u).or_default().push((*v, i));
+        if !directed {
+            incident.entry(*v).or_default().push((*u, i));
+        } else {
+            incident.entry(*v).or_default();
+        }
+    }
+    let mut next: HashMap<Vertex, usize> = incident.keys().map(|v| (*v, 0)).collect();
+
+    let mut used = vec![false; edges.len()];
+    let mut stack: Vec<(Vertex, Option<Edge<Vertex, Weight>>)> = vec![(start, None)];
+    let mut trail = vec![];
+
+    while let Some((u, e)) = stack.last().cloned() {
+        let list = &incident[&u];
+        let i = next.get_mut(&u).unwrap();
+        while *i < list.len() && used[list[*i].1] {
+            *i += 1;
+        }
+
+        match list.get(*i) {
+            Some((v, k)) => {
+                used[*k] = true;
+                stack.push((*v, Some((u, *v, edges[*k].2))));
+            }
+            None => {
+                stack.pop();
+                trail.extend(e);
+            }
+        }
+    }
+
+    if trail.len() < edges.len() {
+        return Err(EulerError::Disconnected(components(edges)));
+    }
+    trail.reverse();
+
+    Ok(trail)
+}
+
+fn components(edges: &[Edge<Vertex, Weight>]) -> Vec<Vec<Vertex>> {
+    let vertices: HashSet<Vertex> = edges.iter().flat_map(|(u, v, _)| [*u, *v]).collect();
+    let mut uf = UnionFind::from(&vertices);
+    for (u, v, _) in edges {
+        uf.union(u, v);
+    }
+
+    let mut groups: HashMap<Vertex, Vec<Vertex>> = HashMap::new();
+    for v in &vertices {
+        groups.entry(*uf.find(v).unwrap()).or_default().push(*v);
+    }
+    let mut components: Vec<Vec<Vertex>> = groups.into_values().collect();
+    for component in components.iter_mut() {
+        component.sort();
+    }
+    components.sort();
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+    /// Consecutive edges meet and every edge of `edges` is used exactly once.
+    fn is_trail(trail: &Path<Vertex, Weight>, edges: &[Edge<Vertex, Weight>], directed: bool) {
+        assert!(trail.windows(2).all(|w| w[0].1 == w[1].0));
+
+        let key = |(u, v, w): &Edge<Vertex, Weight>| {
+            if directed {
+                (*u, *v, *w)
+            } else {
+                (*u.min(v), *u.max(v), *w)
+            }
+        };
+        let mut walked: Vec<Edge<Vertex, Weight>> = trail.iter().map(key).collect();
+        let mut expected: Vec<Edge<Vertex, Weight>> = edges.iter().map(key).collect();
+        walked.sort();
+        expected.sort();
+        assert_eq!(expected, walked);
+    }
+
+    #[test]
+    fn undirected_circuit() {
+        // Two triangles sharing the vertex 3
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 1, 3);
+        g.add_edge(3, 4, 4);
+        g.add_edge(4, 5, 5);
+        g.add_edge(5, 3, 6);
+
+        let current = eulerian_circuit(&g).unwrap();
+        assert_eq!(1, current[0].0);
+        assert_eq!(1, current[5].1);
+        is_trail(&current, &sorted_edges(&g), false);
+    }
+
+    #[test]
+    fn undirected_path() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 2, 1);
+
+        assert_eq!(Err(EulerError::OddDegree(vec![1, 2])), eulerian_circuit(&g));
+
+        let current = eulerian_path(&g).unwrap();
+        assert_eq!(1, current[0].0);
+        assert_eq!(2, current[3].1);
+        is_trail(&current, &sorted_edges(&g), false);
+    }
+
+    #[test]
+    fn too_many_odd_vertices() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(1, 4, 1);
+        g.add_edge(1, 5, 1);
+        g.add_edge(5, 6, 1);
+
+        assert_eq!(
+            Err(EulerError::OddDegree(vec![2, 3, 4, 6])),
+            eulerian_path(&g)
+        );
+    }
+
+    #[test]
+    fn disconnected() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+        g.add_edge(5, 6, 1);
+        g.add_edge(6, 7, 1);
+        g.add_edge(7, 5, 1);
+
+        let expected = EulerError::Disconnected(vec![vec![1, 2, 3], vec![5, 6, 7]]);
+        assert_eq!(Err(expected.clone()), eulerian_circuit(&g));
+        assert_eq!(Err(expected), eulerian_path(&g));
+    }
+
+    #[test]
+    fn directed_circuit_and_path() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 1, 3);
+        g.add_edge(3, 4, 4);
+        g.add_edge(4, 3, 5);
+
+        let current = eulerian_circuit(&g).unwrap();
+        assert_eq!(1, current[0].0);
+        is_trail(&current, &sorted_edges(&g), true);
+
+        g.delete_edge(&4, &3);
+        assert_eq!(
+            Err(EulerError::Unbalanced(vec![(3, 1), (4, -1)])),
+            eulerian_circuit(&g)
+        );
+        let current = eulerian_path(&g).unwrap();
+        assert_eq!(3, current[0].0);
+        assert_eq!(4, current[3].1);
+        is_trail(&current, &sorted_edges(&g), true);
+    }
+
+    #[test]
+    fn directed_unbalanced_by_two() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+
+        assert_eq!(
+            Err(EulerError::Unbalanced(vec![(1, 2), (2, -1), (3, -1)])),
+            eulerian_path(&g)
+        );
+    }
+
+    #[test]
+    fn parallel_edges_and_loops() {
+        let edges = vec![(1, 2, 1), (2, 3, 1), (1, 2, 2), (2, 3, 2), (3, 3, 7)];
+
+        let current = circuit(&edges, false, Some(&2)).unwrap();
+        assert_eq!(2, current[0].0);
+        assert_eq!(2, current[4].1);
+        is_trail(&current, &edges, false);
+    }
+
+    #[test]
+    fn doubled_path() {
+        let edges = vec![(1, 2, 1), (2, 3, 1), (1, 2, 1), (2, 3, 1)];
+
+        let current = circuit(&edges, false, Some(&1)).unwrap();
+        assert_eq!(4, current.len());
+        assert_eq!(1, current[0].0);
+        assert_eq!(1, current[3].1);
+    }
+
+    #[test]
+    fn no_edges() {
+        assert_eq!(Ok(vec![]), trail(&[], false));
+        assert_eq!(Ok(vec![]), circuit(&[], true, None));
+        assert_eq!(Ok(vec![]), circuit(&[], false, Some(&1)));
+    }
+
+    #[test]
+    fn long_cycle() {
+        let n = 200_000;
+        let edges: Vec<Edge<Vertex, Weight>> = (0..n).map(|i| (i, (i + 1) % n, 1)).collect();
+
+        let current = circuit(&edges, true, None).unwrap();
+        assert_eq!(n, current.len());
+        assert_eq!((0, 1, 1), current[0]);
+    }
+}
@@ -0,0 +1,314 @@
+pub mod dinic;
+pub mod edmonds_karp;
+pub mod min_cost_flow;
+pub mod push_relabel;
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::graph::{
+    graph::{Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+/// Maximum s-t flow. `flow` has every edge of the graph with the flow it
+/// carries as weight; an undirected edge is oriented the way its flow goes.
+/// `source_side` is the source side of a minimum cut and `cut` its edges,
+/// with their capacities.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MaxFlow {
+    pub value: Weight,
+    pub flow: Path<Vertex, Weight>,
+    pub source_side: HashSet<Vertex>,
+    pub cut: Path<Vertex, Weight>,
+}
+
+/// Residual network of a capacity graph. Edge k of `edges` becomes the arcs
+/// 2k and 2k + 1, forward and backward, each the reverse of the other; both
+/// have the capacity of an undirected edge.
+pub struct Residual {
+    pub edges: Vec<(Vertex, Vertex, Weight)>,
+    pub vertices: Vec<Vertex>,
+    pub index: HashMap<Vertex, usize>,
+    pub adj: Vec<Vec<usize>>,
+    pub head: Vec<usize>,
+    pub capacity: Vec<Weight>,
+    directed: bool,
+}
+
+impl Residual {
+    pub fn new(g: &dyn Graph<Vertex, Weight>) -> Residual {
+        let mut vertices: Vec<Vertex> = g.get_vertices().iter().copied().collect();
+        vertices.sort();
+        let index: HashMap<Vertex, usize> =
+            vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut edges: Vec<(Vertex, Vertex, Weight)> = g.get_edges().iter().copied().collect();
+        edges.sort();
+
+        let mut r = Residual {
+            adj: vec![vec![]; vertices.len()],
+            head: vec![],
+            capacity: vec![],
+            edges,
+            vertices,
+            index,
+            directed: g.is_directed(),
+        };
+        for (u, v, c) in r.edges.clone() {
+            if c < 0 {
+                panic!("Capacities must be non-negative");
+            }
+            let (i, j) = (r.index[&u], r.index[&v]);
+            r.adj[i].push(r.head.len());
+            r.head.push(j);
+            r.capacity.push(c);
+            r.adj[j].push(r.head.len());
+            r.head.push(i);
+            r.capacity.push(if r.directed { 0 } else { c });
+        }
+
+        r
+    }
+
+    /// Indexes of the source and sink, which must be distinct vertices.
+    pub fn terminals(&self, s: &Vertex, t: &Vertex) -> (usize, usize) {
+        match (self.index.get(s), self.index.get(t)) {
+            (Some(s), Some(t)) if s != t => (*s, *t),
+            _ => panic!("Source and sink must be distinct vertices of the graph"),
+        }
+    }
+
+    pub fn push(&mut self, arc: usize, amount: Weight) {
+        self.capacity[arc] -= amount;
+        self.capacity[arc ^ 1] += amount;
+    }
+
+    /// BFS distances from `s` over arcs with residual capacity, `None` where
+    /// unreachable.
+    pub fn levels(&self, s: usize) -> Vec<Option<usize>> {
+        let mut level = vec![None; self.vertices.len()];
+        let mut queue = VecDeque::from([s]);
+        level[s] = Some(0);
+
+        while let Some(u) = queue.pop_front() {
+            for &arc in &self.adj[u] {
+                let v = self.head[arc];
+                if self.capacity[arc] > 0 && level[v].is_none() {
+                    level[v] = Some(level[u].unwrap() + 1);
+                    queue.push_back(v);
+                }
+            }
+        }
+
+        level
+    }
+
+    /// Reads the flow off the residual capacities once no augmenting path is
+    /// left. The vertices still reachable from `s` are a minimum cut.
+    pub fn max_flow(&self, s: usize) -> MaxFlow {
+        let mut flow = vec![];
+        let mut value = 0;
+
+        for (k, (u, v, c)) in self.edges.iter().enumerate() {
+            let f = c - self.capacity[2 * k];
+            flow.push(if f >= 0 { (*u, *v, f) } else { (*v, *u, -f) });
+
+            let (i, j) = (self.index[u], self.index[v]);
+            if i == s {
+                value += f;
+            }
+            if j == s {
+                value -= f;
+            }
+        }
+        flow.sort();
+
+        let level = self.levels(s);
+        let source_side: HashSet<Vertex> = (0..self.vertices.len())
+            .filter(|i| level[*i].is_some())
+            .map(|i| self.vertices[i])
+            .collect();
+        let cut = self
+            .edges
+            .iter()
+            .filter(|(u, v, _)| {
+                let (su, sv) = (source_side.contains(u), source_side.contains(v));
+                su && !sv || !self.directed && sv && !su
+            })
+            .copied()
+            .collect();
+
+        MaxFlow {
+            value,
+            flow,
+            source_side,
+            cut,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::flow::{
+        dinic::dinic, edmonds_karp::edmonds_karp, push_relabel::push_relabel,
+    };
+    use crate::graph::{
+        directed_graph::DirectedGraph,
+        generators::{rng::Rng, testing::random_edges},
+        undirected_graph::UndirectedGraph,
+    };
+
+    type Solver = fn(&dyn Graph<Vertex, Weight>, &Vertex, &Vertex) -> MaxFlow;
+
+    const SOLVERS: [Solver; 3] = [edmonds_karp, dinic, push_relabel];
+
+    /// Capacity and conservation constraints, and a cut as heavy as the flow.
+    fn check(g: &dyn Graph<Vertex, Weight>, s: Vertex, t: Vertex, res: &MaxFlow) {
+        let mut net: HashMap<Vertex, Weight> = HashMap::new();
+        assert_eq!(g.get_edges().len(), res.flow.len());
+        for (u, v, f) in &res.flow {
+            let capacity = g.get_weight(u, v).copied();
+            assert!(*f >= 0 && Some(*f) <= capacity);
+            *net.entry(*u).or_insert(0) -= f;
+            *net.entry(*v).or_insert(0) += f;
+        }
+        for (v, balance) in &net {
+            if *v != s && *v != t {
+                assert_eq!(0, *balance);
+            }
+        }
+        assert_eq!(-res.value, net[&s]);
+
+        assert!(res.source_side.contains(&s) && !res.source_side.contains(&t));
+        assert_eq!(res.value, res.cut.iter().map(|e| e.2).sum::<Weight>());
+    }
+
+    /// Lightest cut over every subset containing the source.
+    fn brute_force_min_cut(g: &dyn Graph<Vertex, Weight>, s: Vertex, t: Vertex) -> Weight {
+        let vertices: Vec<Vertex> = g.get_vertices().iter().copied().collect();
+        let mut best = Weight::MAX;
+
+        for mask in 0..1u32 << vertices.len() {
+            let side: HashSet<Vertex> = (0..vertices.len())
+                .filter(|i| mask >> i & 1 == 1)
+                .map(|i| vertices[i])
+                .collect();
+            if !side.contains(&s) || side.contains(&t) {
+                continue;
+            }
+            let weight = g
+                .get_edges()
+                .iter()
+                .filter(|(u, v, _)| {
+                    side.contains(u) && !side.contains(v)
+                        || !g.is_directed() && side.contains(v) && !side.contains(u)
+                })
+                .map(|e| e.2)
+                .sum();
+            best = best.min(weight);
+        }
+
+        best
+    }
+
+    #[test]
+    fn textbook_network() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 16);
+        g.add_edge(1, 3, 13);
+        g.add_edge(2, 4, 12);
+        g.add_edge(3, 2, 4);
+        g.add_edge(3, 5, 14);
+        g.add_edge(4, 3, 9);
+        g.add_edge(4, 6, 20);
+        g.add_edge(5, 4, 7);
+        g.add_edge(5, 6, 4);
+
+        for solver in SOLVERS {
+            let res = solver(&g, &1, &6);
+
+            assert_eq!(23, res.value);
+            assert_eq!(HashSet::from([1, 2, 3, 5]), res.source_side);
+            assert_eq!(vec![(2, 4, 12), (5, 4, 7), (5, 6, 4)], res.cut);
+            check(&g, 1, 6, &res);
+        }
+    }
+
+    #[test]
+    fn undirected_network() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(1, 3, 2);
+        g.add_edge(2, 3, 5);
+        g.add_edge(2, 4, 2);
+        g.add_edge(3, 4, 3);
+
+        for solver in SOLVERS {
+            let res = solver(&g, &1, &4);
+
+            assert_eq!(5, res.value);
+            check(&g, 1, 4, &res);
+        }
+    }
+
+    #[test]
+    fn disconnected_sink() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(3, 4, 3);
+
+        for solver in SOLVERS {
+            let res = solver(&g, &1, &4);
+
+            assert_eq!(0, res.value);
+            assert!(res.cut.is_empty());
+            assert_eq!(HashSet::from([1, 2]), res.source_side);
+        }
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut rng = Rng::new(41);
+
+        for round in 0..150 {
+            let mut g: Box<dyn Graph<Vertex, Weight>> = if round % 2 == 0 {
+                Box::new(DirectedGraph::new())
+            } else {
+                Box::new(UndirectedGraph::new())
+            };
+            random_edges(g.as_mut(), 8, 16, &(0..=9), &mut rng);
+            let (s, t) = (rng.below(8), rng.below(8));
+            if s == t || !g.get_vertices().contains(&s) || !g.get_vertices().contains(&t) {
+                continue;
+            }
+
+            let expected = brute_force_min_cut(g.as_ref(), s, t);
+            for solver in SOLVERS {
+                let res = solver(g.as_ref(), &s, &t);
+                assert_eq!(expected, res.value);
+                check(g.as_ref(), s, t, &res);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_capacity() {
+        let mut g = DirectedGraph::new();
+        g.add_edge(1, 2, -1);
+
+        edmonds_karp(&g, &1, &2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn same_source_and_sink() {
+        let mut g = DirectedGraph::new();
+        g.add_edge(1, 2, 1);
+
+        dinic(&g, &1, &1);
+    }
+}
@@ -0,0 +1,65 @@
+use crate::algorithms::flow::{MaxFlow, Residual};
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> MaxFlow {
+    dinic(g, s, t)
+}
+
+/// Blocking flows over the BFS level graph, each found with an iterative DFS
+/// that skips arcs leading nowhere. O(n^2 m).
+pub fn dinic(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> MaxFlow {
+    let mut r = Residual::new(g);
+    let (s, t) = r.terminals(s, t);
+
+    loop {
+        let level = r.levels(s);
+        if level[t].is_none() {
+            break;
+        }
+
+        // Next arc to try from every vertex
+        let mut next = vec![0; r.vertices.len()];
+        let mut path: Vec<usize> = vec![];
+        let mut u = s;
+        loop {
+            if u == t {
+                let amount = path.iter().map(|arc| r.capacity[*arc]).min().unwrap();
+                for arc in &path {
+                    r.push(*arc, amount);
+                }
+                path.clear();
+                u = s;
+                continue;
+            }
+
+            let admissible = r.adj[u][next[u]..].iter().position(|&arc| {
+                let v = r.head[arc];
+                r.capacity[arc] > 0 && level[v] == level[u].map(|l| l + 1)
+            });
+            match admissible {
+                Some(offset) => {
+                    next[u] += offset;
+                    let arc = r.adj[u][next[u]];
+                    path.push(arc);
+                    u = r.head[arc];
+                }
+                None => {
+                    // Dead end: retreat and never come back through this arc
+                    next[u] = r.adj[u].len();
+                    match path.pop() {
+                        Some(arc) => {
+                            u = r.head[arc ^ 1];
+                            next[u] += 1;
+                        }
+                        None => break,
+                    }
+                }
+            }
+        }
+    }
+
+    r.max_flow(s)
+}
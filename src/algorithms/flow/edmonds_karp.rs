@@ -0,0 +1,47 @@
+use std::collections::VecDeque;
+
+use crate::algorithms::flow::{MaxFlow, Residual};
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> MaxFlow {
+    edmonds_karp(g, s, t)
+}
+
+/// Augments along shortest residual paths, found with BFS. O(nm^2).
+pub fn edmonds_karp(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> MaxFlow {
+    let mut r = Residual::new(g);
+    let (s, t) = r.terminals(s, t);
+
+    loop {
+        let mut parent_arc: Vec<Option<usize>> = vec![None; r.vertices.len()];
+        let mut queue = VecDeque::from([s]);
+        while let Some(u) = queue.pop_front() {
+            for &arc in &r.adj[u] {
+                let v = r.head[arc];
+                if r.capacity[arc] > 0 && v != s && parent_arc[v].is_none() {
+                    parent_arc[v] = Some(arc);
+                    queue.push_back(v);
+                }
+            }
+        }
+        if parent_arc[t].is_none() {
+            break;
+        }
+
+        let mut path = vec![];
+        let mut v = t;
+        while let Some(arc) = parent_arc[v] {
+            path.push(arc);
+            v = r.head[arc ^ 1];
+        }
+        let amount = path.iter().map(|arc| r.capacity[*arc]).min().unwrap();
+        for arc in path {
+            r.push(arc, amount);
+        }
+    }
+
+    r.max_flow(s)
+}
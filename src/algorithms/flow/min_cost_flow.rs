@@ -0,0 +1,371 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+use crate::graph::{
+    graph::Path,
+    undirected_graph::{Vertex, Weight},
+};
+
+/// A directed edge with its tail, head, capacity and cost per unit of flow.
+pub type CostEdge = (Vertex, Vertex, Weight, Weight);
+
+/// `flow` has every edge, in the order given, with the flow it carries as
+/// weight. The cost is summed in 64 bits.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MinCostFlow {
+    pub value: Weight,
+    pub cost: i64,
+    pub flow: Path<Vertex, Weight>,
+}
+
+struct Network {
+    adj: Vec<Vec<usize>>,
+    head: Vec<usize>,
+    capacity: Vec<Weight>,
+    cost: Vec<Weight>,
+}
+
+impl Network {
+    fn new(n: usize) -> Network {
+        Network {
+            adj: vec![vec![]; n],
+            head: vec![],
+            capacity: vec![],
+            cost: vec![],
+        }
+    }
+
+    /// Adds the arc and its reverse, returning the index of the arc.
+    fn add_arc(&mut self, u: usize, v: usize, capacity: Weight, cost: Weight) -> usize {
+        for (from, to, capacity, cost) in [(u, v, capacity, cost), (v, u, 0, -cost)] {
+            self.adj[from].push(self.head.len());
+            self.head.push(to);
+            self.capacity.push(capacity);
+            self.cost.push(cost);
+        }
+
+        self.head.len() - 2
+    }
+
+    fn push(&mut self, arc: usize, amount: Weight) {
+        self.capacity[arc] -= amount;
+        self.capacity[arc ^ 1] += amount;
+    }
+}
+
+pub fn run(
+    edges: &[CostEdge],
+    s: &Vertex,
+    t: &Vertex,
+    amount: Option<Weight>,
+) -> Option<MinCostFlow> {
+    min_cost_flow(edges, s, t, amount)
+}
+
+/// Cheapest s-t flow of the required `amount`, or of the maximum value when
+/// there is none; `None` when the amount cannot be sent. Negative costs,
+/// negative cycles included, are handled by saturating those edges first:
+/// the residual costs are then non-negative, and successive shortest paths
+/// with Dijkstra and potentials route the resulting imbalances together with
+/// the s-t flow, from a super source to a super sink.
+pub fn min_cost_flow(
+    edges: &[CostEdge],
+    s: &Vertex,
+    t: &Vertex,
+    amount: Option<Weight>,
+) -> Option<MinCostFlow> {
+    let mut vertices: Vec<Vertex> = edges.iter().flat_map(|(u, v, _, _)| [*u, *v]).collect();
+    vertices.sort();
+    vertices.dedup();
+    let index: HashMap<Vertex, usize> = vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+    let (s, t) = match (index.get(s), index.get(t)) {
+        (Some(s), Some(t)) if s != t => (*s, *t),
+        _ => panic!("Source and sink must be distinct vertices of the graph"),
+    };
+    if edges.iter().any(|e| e.2 < 0) {
+        panic!("Capacities must be non-negative");
+    }
+
+    let n = vertices.len();
+    let value = amount.unwrap_or_else(|| max_flow_value(n, edges, &index, s, t));
+    let (source, sink) = (n, n + 1);
+    let mut network = Network::new(n + 2);
+    let mut balance: Vec<Weight> = vec![0; n];
+
+    let arcs: Vec<usize> = edges
+        .iter()
+        .map(|(u, v, capacity, cost)| {
+            let (i, j) = (index[u], index[v]);
+            let arc = network.add_arc(i, j, *capacity, *cost);
+            if *cost < 0 {
+                network.push(arc, *capacity);
+                balance[i] -= capacity;
+                balance[j] += capacity;
+            }
+            arc
+        })
+        .collect();
+    balance[s] += value;
+    balance[t] -= value;
+
+    let mut supply = 0;
+    for (v, b) in balance.iter().enumerate() {
+        if *b > 0 {
+            network.add_arc(source, v, *b, 0);
+            supply += b;
+        } else if *b < 0 {
+            network.add_arc(v, sink, -b, 0);
+        }
+    }
+
+    if successive_shortest_paths(&mut network, source, sink) < supply {
+        return None;
+    }
+
+    let flow: Path<Vertex, Weight> = edges
+        .iter()
+        .zip(arcs)
+        .map(|((u, v, _, _), arc)| (*u, *v, network.capacity[arc ^ 1]))
+        .collect();
+    let cost = edges
+        .iter()
+        .zip(&flow)
+        .map(|((_, _, _, cost), (_, _, f))| *cost as i64 * *f as i64)
+        .sum();
+
+    Some(MinCostFlow { value, cost, flow })
+}
+
+/// Sends as much flow as possible along cheapest paths. Every residual cost
+/// must be non-negative at the start.
+fn successive_shortest_paths(network: &mut Network, source: usize, sink: usize) -> Weight {
+    let n = network.adj.len();
+    let mut potential: Vec<i64> = vec![0; n];
+    let mut sent = 0;
+
+    loop {
+        let mut distance: Vec<Option<i64>> = vec![None; n];
+        let mut parent_arc: Vec<Option<usize>> = vec![None; n];
+        let mut heap = BinaryHeap::from([Reverse((0, source))]);
+        distance[source] = Some(0);
+
+        while let Some(Reverse((d, u))) = heap.pop() {
+            if distance[u] != Some(d) {
+                continue;
+            }
+            for &arc in &network.adj[u] {
+                let v = network.head[arc];
+                if network.capacity[arc] == 0 {
+                    continue;
+                }
+                let reduced = network.cost[arc] as i64 + potential[u] - potential[v];
+                if distance[v].is_none_or(|dv| d + reduced < dv) {
+                    distance[v] = Some(d + reduced);
+                    parent_arc[v] = Some(arc);
+                    heap.push(Reverse((d + reduced, v)));
+                }
+            }
+        }
+
+        if distance[sink].is_none() {
+            return sent;
+        }
+        for v in 0..n {
+            if let Some(d) = distance[v] {
+                potential[v] += d;
+            }
+        }
+
+        let mut path = vec![];
+        let mut v = sink;
+        while let Some(arc) = parent_arc[v] {
+            path.push(arc);
+            v = network.head[arc ^ 1];
+        }
+        let amount = path.iter().map(|arc| network.capacity[*arc]).min().unwrap();
+        for arc in path {
+            network.push(arc, amount);
+        }
+        sent += amount;
+    }
+}
+
+/// Value of a maximum s-t flow, by shortest augmenting paths.
+fn max_flow_value(
+    n: usize,
+    edges: &[CostEdge],
+    index: &HashMap<Vertex, usize>,
+    s: usize,
+    t: usize,
+) -> Weight {
+    let mut network = Network::new(n);
+    for (u, v, capacity, _) in edges {
+        network.add_arc(index[u], index[v], *capacity, 0);
+    }
+    let mut value = 0;
+
+    loop {
+        let mut parent_arc: Vec<Option<usize>> = vec![None; n];
+        let mut queue = VecDeque::from([s]);
+        while let Some(u) = queue.pop_front() {
+            for &arc in &network.adj[u] {
+                let v = network.head[arc];
+                if network.capacity[arc] > 0 && v != s && parent_arc[v].is_none() {
+                    parent_arc[v] = Some(arc);
+                    queue.push_back(v);
+                }
+            }
+        }
+        if parent_arc[t].is_none() {
+            return value;
+        }
+
+        let mut path = vec![];
+        let mut v = t;
+        while let Some(arc) = parent_arc[v] {
+            path.push(arc);
+            v = network.head[arc ^ 1];
+        }
+        let amount = path.iter().map(|arc| network.capacity[*arc]).min().unwrap();
+        for arc in path {
+            network.push(arc, amount);
+        }
+        value += amount;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::flow::edmonds_karp::edmonds_karp;
+    use crate::graph::{
+        directed_graph::DirectedGraph,
+        generators::{rng::Rng, testing::random_edges},
+        graph::Graph,
+    };
+
+    /// Conservation and capacities, and no negative cycle in the residual
+    /// network, which makes the flow cheapest for its value.
+    fn check(edges: &[CostEdge], s: Vertex, t: Vertex, res: &MinCostFlow) {
+        let mut balance: HashMap<Vertex, Weight> = HashMap::new();
+        let mut residual: Vec<(Vertex, Vertex, i64)> = vec![];
+
+        for ((u, v, capacity, cost), (_, _, f)) in edges.iter().zip(&res.flow) {
+            assert!(0 <= *f && f <= capacity);
+            *balance.entry(*u).or_insert(0) -= f;
+            *balance.entry(*v).or_insert(0) += f;
+            if f < capacity {
+                residual.push((*u, *v, *cost as i64));
+            }
+            if *f > 0 {
+                residual.push((*v, *u, -*cost as i64));
+            }
+        }
+        for (v, b) in &balance {
+            let expected = if *v == s {
+                -res.value
+            } else if *v == t {
+                res.value
+            } else {
+                0
+            };
+            assert_eq!(expected, *b);
+        }
+
+        let mut distance: HashMap<Vertex, i64> = balance.keys().map(|v| (*v, 0)).collect();
+        for _ in 0..distance.len() {
+            for (u, v, c) in &residual {
+                if distance[u] + c < distance[v] {
+                    distance.insert(*v, distance[u] + c);
+                }
+            }
+        }
+        assert!(residual
+            .iter()
+            .all(|(u, v, c)| distance[u] + c >= distance[v]));
+    }
+
+    #[test]
+    fn cheaper_longer_path() {
+        let edges = [
+            (1, 2, 2, 1),
+            (1, 3, 1, 5),
+            (2, 3, 1, 1),
+            (2, 4, 1, 6),
+            (3, 4, 2, 1),
+        ];
+
+        let res = min_cost_flow(&edges, &1, &4, Some(2)).unwrap();
+        assert_eq!(2, res.value);
+        assert_eq!(
+            vec![(1, 2, 1), (1, 3, 1), (2, 3, 1), (2, 4, 0), (3, 4, 2)],
+            res.flow
+        );
+        assert_eq!(1 + 5 + 1 + 2, res.cost);
+        check(&edges, 1, 4, &res);
+
+        let max = min_cost_flow(&edges, &1, &4, None).unwrap();
+        assert_eq!(3, max.value);
+        assert_eq!(2 + 5 + 1 + 6 + 2, max.cost);
+        check(&edges, 1, 4, &max);
+    }
+
+    #[test]
+    fn required_amount_too_large() {
+        let edges = [(1, 2, 3, 1), (2, 3, 2, 1)];
+
+        assert_eq!(None, min_cost_flow(&edges, &1, &3, Some(3)));
+    }
+
+    #[test]
+    fn negative_cycle_is_used() {
+        // Sending nothing from 1 to 3 still pays off around 2 -> 4 -> 2
+        let edges = [(1, 2, 1, 1), (2, 3, 1, 1), (2, 4, 5, -3), (4, 2, 5, 1)];
+
+        let res = min_cost_flow(&edges, &1, &3, Some(0)).unwrap();
+        assert_eq!(-10, res.cost);
+        assert_eq!(vec![(1, 2, 0), (2, 3, 0), (2, 4, 5), (4, 2, 5)], res.flow);
+        check(&edges, 1, 3, &res);
+    }
+
+    #[test]
+    fn parallel_edges() {
+        let edges = [(1, 2, 1, 5), (1, 2, 1, -2), (1, 2, 1, 3)];
+
+        let res = min_cost_flow(&edges, &1, &2, Some(2)).unwrap();
+        assert_eq!(1, res.cost);
+        assert_eq!(vec![(1, 2, 0), (1, 2, 1), (1, 2, 1)], res.flow);
+    }
+
+    #[test]
+    fn random_networks() {
+        let mut rng = Rng::new(47);
+
+        for _ in 0..200 {
+            let mut g = DirectedGraph::new();
+            random_edges(&mut g, 7, 14, &(0..=5), &mut rng);
+            // Sorted before drawing the costs, so they follow from the seed
+            let mut edges: Vec<_> = g
+                .get_edges()
+                .iter()
+                .map(|(u, v, c)| (*u, *v, *c, 0))
+                .collect();
+            edges.sort();
+            for e in edges.iter_mut() {
+                e.3 = rng.in_range(&(-10..=10));
+            }
+            let (s, t) = (rng.below(7), rng.below(7));
+            if s == t || !g.get_vertices().contains(&s) || !g.get_vertices().contains(&t) {
+                continue;
+            }
+
+            let max = min_cost_flow(&edges, &s, &t, None).unwrap();
+            assert_eq!(edmonds_karp(&g, &s, &t).value, max.value);
+            check(&edges, s, t, &max);
+
+            let half = min_cost_flow(&edges, &s, &t, Some(max.value / 2)).unwrap();
+            check(&edges, s, t, &half);
+            assert_eq!(None, min_cost_flow(&edges, &s, &t, Some(max.value + 1)));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::VecDeque;
+
+use crate::algorithms::flow::{MaxFlow, Residual};
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> MaxFlow {
+    push_relabel(g, s, t)
+}
+
+/// Goldberg-Tarjan preflow push with FIFO selection of active vertices.
+/// Excess that cannot reach the sink is pushed back to the source once the
+/// heights rise above n, leaving a valid flow. Heights are reset to exact
+/// residual distances every n relabels. O(n^3).
+pub fn push_relabel(g: &dyn Graph<Vertex, Weight>, s: &Vertex, t: &Vertex) -> MaxFlow {
+    let mut r = Residual::new(g);
+    let (s, t) = r.terminals(s, t);
+    let n = r.vertices.len();
+
+    let mut excess: Vec<Weight> = vec![0; n];
+    let mut current = vec![0; n];
+    let mut active: VecDeque<usize> = VecDeque::new();
+
+    for arc in r.adj[s].clone() {
+        let amount = r.capacity[arc];
+        let v = r.head[arc];
+        if amount > 0 && v != s {
+            r.push(arc, amount);
+            excess[v] += amount;
+            excess[s] -= amount;
+            if v != t && excess[v] == amount {
+                active.push_back(v);
+            }
+        }
+    }
+    let mut height = global_relabel(&r, s, t);
+    let mut relabels = 0;
+
+    while let Some(u) = active.pop_front() {
+        while excess[u] > 0 {
+            if current[u] == r.adj[u].len() {
+                height[u] = r.adj[u]
+                    .iter()
+                    .filter(|arc| r.capacity[**arc] > 0)
+                    .map(|arc| height[r.head[*arc]] + 1)
+                    .min()
+                    .unwrap();
+                current[u] = 0;
+
+                relabels += 1;
+                if relabels % n == 0 {
+                    height = global_relabel(&r, s, t);
+                }
+                continue;
+            }
+
+            let arc = r.adj[u][current[u]];
+            let v = r.head[arc];
+            if r.capacity[arc] > 0 && height[u] == height[v] + 1 {
+                let amount = excess[u].min(r.capacity[arc]);
+                r.push(arc, amount);
+                excess[u] -= amount;
+                excess[v] += amount;
+                if v != s && v != t && excess[v] == amount {
+                    active.push_back(v);
+                }
+            } else {
+                current[u] += 1;
+            }
+        }
+    }
+
+    r.max_flow(s)
+}
+
+/// Residual distance to the sink, or n plus the distance to the source for
+/// vertices that cannot reach the sink any more.
+fn global_relabel(r: &Residual, s: usize, t: usize) -> Vec<usize> {
+    let n = r.vertices.len();
+    let mut height = vec![2 * n; n];
+
+    for (root, base) in [(t, 0), (s, n)] {
+        if height[root] < 2 * n {
+            continue;
+        }
+        height[root] = base;
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            for &arc in &r.adj[v] {
+                let u = r.head[arc];
+                // u reaches v through the reverse of arc
+                if r.capacity[arc ^ 1] > 0 && height[u] == 2 * n && u != s {
+                    height[u] = height[v] + 1;
+                    queue.push_back(u);
+                }
+            }
+        }
+    }
+
+    height
+}
@@ -0,0 +1,5 @@
+pub mod blossom;
+pub mod hopcroft_karp;
+pub mod hungarian;
+pub mod is_matching;
+pub mod weighted_matching;
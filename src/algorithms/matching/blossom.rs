@@ -0,0 +1,258 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::algorithms::matching::weighted_matching::Matching;
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+const NONE: usize = usize::MAX;
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Matching {
+    maximum_matching(g)
+}
+
+/// Maximum-cardinality matching of a general graph with Edmonds' blossom
+/// algorithm in O(n³). A greedy matching is grown by one BFS per free vertex;
+/// odd cycles met by the search are contracted into their base. Edges are
+/// listed with their endpoints sorted.
+pub fn maximum_matching(g: &dyn Graph<Vertex, Weight>) -> Matching {
+    let mut vertices: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+    vertices.sort();
+    let indexes: HashMap<Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+    let adj: Vec<Vec<usize>> = vertices
+        .iter()
+        .map(|u| {
+            let mut neighbours: Vec<usize> = g
+                ._get_adj_list(u)
+                .unwrap()
+                .keys()
+                .filter(|v| *v != u)
+                .map(|v| indexes[v])
+                .collect();
+            neighbours.sort();
+            neighbours
+        })
+        .collect();
+
+    let mut search = Blossom::new(adj);
+    for v in 0..vertices.len() {
+        if search.mate[v] == NONE {
+            if let Some(u) = search.adj[v].iter().find(|u| search.mate[**u] == NONE) {
+                search.mate[*u] = v;
+                search.mate[v] = *u;
+            }
+        }
+    }
+    for root in 0..vertices.len() {
+        if search.mate[root] == NONE {
+            if let Some(free) = search.find_path(root) {
+                search.augment(free);
+            }
+        }
+    }
+
+    (0..vertices.len())
+        .filter(|v| search.mate[*v] != NONE && *v < search.mate[*v])
+        .map(|v| {
+            let (a, b) = (vertices[v], vertices[search.mate[v]]);
+            (a, b, *g.get_weight(&a, &b).unwrap())
+        })
+        .collect()
+}
+
+struct Blossom {
+    adj: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    /// Tree parent of the odd vertices of the alternating forest.
+    parent: Vec<usize>,
+    /// Base of the outermost contracted blossom containing the vertex.
+    base: Vec<usize>,
+    even: Vec<bool>,
+}
+
+impl Blossom {
+    fn new(adj: Vec<Vec<usize>>) -> Blossom {
+        let n = adj.len();
+        Blossom {
+            adj,
+            mate: vec![NONE; n],
+            parent: vec![NONE; n],
+            base: (0..n).collect(),
+            even: vec![false; n],
+        }
+    }
+
+    /// Grows an alternating tree from `root`, returning the free vertex that
+    /// ends an augmenting path if there is one.
+    fn find_path(&mut self, root: usize) -> Option<usize> {
+        let n = self.adj.len();
+        self.parent.fill(NONE);
+        self.even.fill(false);
+        for (v, base) in self.base.iter_mut().enumerate() {
+            *base = v;
+        }
+
+        self.even[root] = true;
+        let mut queue = VecDeque::from([root]);
+        while let Some(v) = queue.pop_front() {
+            for i in 0..self.adj[v].len() {
+                let to = self.adj[v][i];
+                if self.base[v] == self.base[to] || self.mate[v] == to {
+                    continue;
+                }
+
+                if to == root || (self.mate[to] != NONE && self.parent[self.mate[to]] != NONE) {
+                    // Both ends are even: the edge closes an odd cycle
+                    let base = self.common_base(v, to);
+                    let mut in_blossom = vec![false; n];
+                    self.mark_path(v, base, to, &mut in_blossom);
+                    self.mark_path(to, base, v, &mut in_blossom);
+
+                    for u in 0..n {
+                        if in_blossom[self.base[u]] {
+                            self.base[u] = base;
+                            if !self.even[u] {
+                                self.even[u] = true;
+                                queue.push_back(u);
+                            }
+                        }
+                    }
+                } else if self.parent[to] == NONE {
+                    self.parent[to] = v;
+                    if self.mate[to] == NONE {
+                        return Some(to);
+                    }
+
+                    let next = self.mate[to];
+                    self.even[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Base of the blossom closed by the edge (a, b): the first blossom
+    /// shared by the paths of both towards the root.
+    fn common_base(&self, mut a: usize, mut b: usize) -> usize {
+        let mut on_path = vec![false; self.adj.len()];
+
+        loop {
+            a = self.base[a];
+            on_path[a] = true;
+            if self.mate[a] == NONE {
+                break;
+            }
+            a = self.parent[self.mate[a]];
+        }
+
+        loop {
+            b = self.base[b];
+            if on_path[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b]];
+        }
+    }
+
+    /// Marks the blossoms from `v` up to `base` and points the odd vertices on
+    /// the way back across the cycle, so the blossom can be walked either way.
+    fn mark_path(&mut self, mut v: usize, base: usize, mut child: usize, in_blossom: &mut [bool]) {
+        while self.base[v] != base {
+            in_blossom[self.base[v]] = true;
+            in_blossom[self.base[self.mate[v]]] = true;
+            self.parent[v] = child;
+            child = self.mate[v];
+            v = self.parent[self.mate[v]];
+        }
+    }
+
+    fn augment(&mut self, mut v: usize) {
+        while v != NONE {
+            let p = self.parent[v];
+            let next = self.mate[p];
+            self.mate[v] = p;
+            self.mate[p] = v;
+            v = next;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::matching::is_matching::{is_matching, is_perfect_matching};
+    use crate::algorithms::matching::weighted_matching::max_weight_matching;
+    use crate::graph::{
+        generators::{rng::Rng, testing::random_edges},
+        undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn odd_cycle_with_stem() {
+        // The greedy start matches 2-3 and 4-5; growing from 1 has to go
+        // through the blossom 2-3-4-5-6
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, 3);
+        g.add_edge(4, 5, 4);
+        g.add_edge(5, 6, 5);
+        g.add_edge(6, 2, 6);
+        g.add_edge(4, 7, 7);
+
+        let current = maximum_matching(&g);
+        assert_eq!(3, current.len());
+        assert!(is_matching(&g, &current));
+    }
+
+    #[test]
+    fn petersen_has_perfect_matching() {
+        let mut g = UndirectedGraph::new();
+
+        for i in 0..5 {
+            g.add_edge(i, (i + 1) % 5, 1);
+            g.add_edge(i, i + 5, 1);
+            g.add_edge(i + 5, (i + 2) % 5 + 5, 1);
+        }
+
+        let current = maximum_matching(&g);
+        assert!(is_perfect_matching(&g, &current));
+    }
+
+    #[test]
+    fn star() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(1, 3, 4);
+        g.add_edge(1, 4, 5);
+        g.add_edge(4, 4, 1);
+
+        assert_eq!(vec![(1, 2, 3)], maximum_matching(&g));
+    }
+
+    #[test]
+    fn matches_weighted_blossom() {
+        let mut rng = Rng::new(47);
+
+        for _ in 0..300 {
+            let n = 2 + rng.below(14);
+            let mut g = UndirectedGraph::new();
+            let attempts = rng.below(2 * n) + 1;
+            random_edges(&mut g, n, attempts, &(1..=1), &mut rng);
+            if g.get_vertices().is_empty() {
+                continue;
+            }
+
+            let current = maximum_matching(&g);
+            assert!(is_matching(&g, &current));
+            assert_eq!(max_weight_matching(&g, true).len(), current.len());
+        }
+    }
+}
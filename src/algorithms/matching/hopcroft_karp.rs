@@ -0,0 +1,331 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::algorithms::bipartite::is_bipartite::{is_bipartite, Bipartiteness};
+use crate::algorithms::matching::weighted_matching::Matching;
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+/// Edges of `matching` go from the left side to the right one and are sorted
+/// by their left endpoint. `cover` is a minimum vertex cover of the same size.
+#[derive(Debug, PartialEq, Eq)]
+pub struct BipartiteMatching {
+    pub matching: Matching,
+    pub cover: HashSet<Vertex>,
+}
+
+const NONE: usize = usize::MAX;
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> BipartiteMatching {
+    hopcroft_karp(g, None)
+}
+
+/// Maximum-cardinality matching of a bipartite graph in O(m √n). `left` is
+/// one side of the bipartition; without it the sides are the ones found by
+/// `is_bipartite`. The cover comes from König's theorem: the left vertices not
+/// reachable from a free left vertex by alternating paths, and the right
+/// vertices that are.
+pub fn hopcroft_karp(
+    g: &dyn Graph<Vertex, Weight>,
+    left: Option<&HashSet<Vertex>>,
+) -> BipartiteMatching {
+    if g.is_directed() {
+        panic!("Hopcroft-Karp needs an undirected graph");
+    }
+
+    let left: HashSet<Vertex> = match left {
+        Some(left) => left.clone(),
+        None => match is_bipartite(g) {
+            Bipartiteness::Bipartite(left, _) => left,
+            Bipartiteness::OddCycle(_) => panic!("The graph is not bipartite"),
+        },
+    };
+
+    let mut lefts: Vec<Vertex> = left.iter().cloned().collect();
+    let mut rights: Vec<Vertex> = g
+        .get_vertices()
+        .iter()
+        .filter(|v| !left.contains(v))
+        .cloned()
+        .collect();
+    lefts.sort();
+    rights.sort();
+    let right_indexes: HashMap<Vertex, usize> =
+        rights.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+    let adj: Vec<Vec<(usize, Weight)>> = lefts
+        .iter()
+        .map(|u| {
+            let mut neighbours: Vec<(usize, Weight)> = g
+                ._get_adj_list(u)
+                .unwrap()
+                .iter()
+                .map(|(v, w)| match right_indexes.get(v) {
+                    Some(j) => (*j, *w),
+                    None => panic!("Edge ({}, {}) does not cross the bipartition", u, v),
+                })
+                .collect();
+            neighbours.sort();
+            neighbours
+        })
+        .collect();
+
+    let mut layers = Layers::new(adj, rights.len());
+    while layers.build() {
+        layers.augment();
+    }
+
+    let matching = (0..lefts.len())
+        .filter(|i| layers.mate_left[*i] != NONE)
+        .map(|i| {
+            let (j, w) = layers.adj[i]
+                .iter()
+                .find(|(j, _)| *j == layers.mate_left[i])
+                .unwrap();
+            (lefts[i], rights[*j], *w)
+        })
+        .collect();
+
+    let (left_reached, right_reached) = layers.alternating_reach();
+    let cover = (0..lefts.len())
+        .filter(|i| !left_reached[*i])
+        .map(|i| lefts[i])
+        .chain(
+            (0..rights.len())
+                .filter(|j| right_reached[*j])
+                .map(|j| rights[j]),
+        )
+        .collect();
+
+    BipartiteMatching { matching, cover }
+}
+
+struct Layers {
+    adj: Vec<Vec<(usize, Weight)>>,
+    mate_left: Vec<usize>,
+    mate_right: Vec<usize>,
+    dist: Vec<usize>,
+    /// Length of the shortest augmenting paths of the current phase.
+    limit: usize,
+}
+
+impl Layers {
+    fn new(adj: Vec<Vec<(usize, Weight)>>, right: usize) -> Layers {
+        let left = adj.len();
+        Layers {
+            adj,
+            mate_left: vec![NONE; left],
+            mate_right: vec![NONE; right],
+            dist: vec![NONE; left],
+            limit: NONE,
+        }
+    }
+
+    /// Layers the left vertices by alternating BFS from the free ones; false
+    /// when no free right vertex is reachable, i.e. the matching is maximum.
+    fn build(&mut self) -> bool {
+        let mut queue = VecDeque::new();
+        for (u, dist) in self.dist.iter_mut().enumerate() {
+            *dist = if self.mate_left[u] == NONE {
+                queue.push_back(u);
+                0
+            } else {
+                NONE
+            };
+        }
+        self.limit = NONE;
+
+        while let Some(u) = queue.pop_front() {
+            if self.dist[u] >= self.limit {
+                break;
+            }
+            for (v, _) in &self.adj[u] {
+                match self.mate_right[*v] {
+                    NONE => self.limit = self.limit.min(self.dist[u] + 1),
+                    w if self.dist[w] == NONE => {
+                        self.dist[w] = self.dist[u] + 1;
+                        queue.push_back(w);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        self.limit != NONE
+    }
+
+    /// Augments along a maximal set of vertex-disjoint shortest paths, with
+    /// an iterative DFS over the layers that never retries a dead arc.
+    fn augment(&mut self) {
+        let mut next = vec![0; self.adj.len()];
+
+        for root in 0..self.adj.len() {
+            if self.mate_left[root] != NONE {
+                continue;
+            }
+
+            let mut stack = vec![root];
+            while let Some(&u) = stack.last() {
+                if next[u] == self.adj[u].len() {
+                    self.dist[u] = NONE;
+                    stack.pop();
+                    continue;
+                }
+
+                let v = self.adj[u][next[u]].0;
+                match self.mate_right[v] {
+                    NONE if self.dist[u] + 1 == self.limit => {
+                        for x in stack.drain(..) {
+                            let y = self.adj[x][next[x]].0;
+                            self.mate_left[x] = y;
+                            self.mate_right[y] = x;
+                            self.dist[x] = NONE;
+                        }
+                    }
+                    w if w != NONE && self.dist[w] == self.dist[u] + 1 => stack.push(w),
+                    _ => next[u] += 1,
+                }
+            }
+        }
+    }
+
+    fn alternating_reach(&self) -> (Vec<bool>, Vec<bool>) {
+        let mut left = vec![false; self.adj.len()];
+        let mut right = vec![false; self.mate_right.len()];
+        let mut queue: VecDeque<usize> = (0..self.adj.len())
+            .filter(|u| self.mate_left[*u] == NONE)
+            .collect();
+        for u in &queue {
+            left[*u] = true;
+        }
+
+        while let Some(u) = queue.pop_front() {
+            for (v, _) in &self.adj[u] {
+                if !right[*v] {
+                    right[*v] = true;
+                    let w = self.mate_right[*v];
+                    if w != NONE && !left[w] {
+                        left[w] = true;
+                        queue.push_back(w);
+                    }
+                }
+            }
+        }
+
+        (left, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::flow::edmonds_karp::edmonds_karp;
+    use crate::algorithms::matching::is_matching::is_matching;
+    use crate::algorithms::vertex_cover::is_vertex_cover::is_vertex_cover;
+    use crate::graph::directed_graph::DirectedGraph;
+    use crate::graph::generators::rng::Rng;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    fn check(g: &UndirectedGraph<Vertex, Weight>, res: &BipartiteMatching) {
+        assert!(is_matching(g, &res.matching));
+        assert_eq!(res.matching.len(), res.cover.len());
+        assert!(is_vertex_cover(g, &res.cover));
+    }
+
+    #[test]
+    fn augmenting_path() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 4, 1);
+        g.add_edge(2, 4, 2);
+        g.add_edge(2, 5, 3);
+        g.add_edge(3, 5, 4);
+        g.add_edge(3, 6, 5);
+
+        let res = hopcroft_karp(&g, None);
+        assert_eq!(vec![(1, 4, 1), (2, 5, 3), (3, 6, 5)], res.matching);
+        check(&g, &res);
+    }
+
+    #[test]
+    fn deficient_side() {
+        // Workers 1, 2 and 3 only know job 4
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 4, 1);
+        g.add_edge(2, 4, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(3, 5, 1);
+
+        let res = hopcroft_karp(&g, Some(&HashSet::from([1, 2, 3])));
+        assert_eq!(2, res.matching.len());
+        assert_eq!(HashSet::from([3, 4]), res.cover);
+        check(&g, &res);
+    }
+
+    #[test]
+    fn explicit_sides() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 2, 1);
+        g.add_edge(3, 4, 1);
+
+        let res = hopcroft_karp(&g, Some(&HashSet::from([2, 4])));
+        assert_eq!(vec![(2, 1, 1), (4, 3, 1)], res.matching);
+        check(&g, &res);
+    }
+
+    #[test]
+    #[should_panic(expected = "The graph is not bipartite")]
+    fn odd_cycle() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 1, 1);
+
+        hopcroft_karp(&g, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not cross the bipartition")]
+    fn edge_inside_a_side() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+
+        hopcroft_karp(&g, Some(&HashSet::from([1, 2])));
+    }
+
+    #[test]
+    fn matches_max_flow() {
+        let mut rng = Rng::new(46);
+
+        for _ in 0..200 {
+            let (a, b) = (1 + rng.below(8), 1 + rng.below(8));
+            let (s, t) = (a + b, a + b + 1);
+            let mut g = UndirectedGraph::new();
+            let mut network = DirectedGraph::new();
+            for _ in 0..rng.below(3 * (a + b)) {
+                let (u, v) = (rng.below(a), a + rng.below(b));
+                if g.get_weight(&u, &v).is_none() {
+                    g.add_edge(u, v, 1);
+                    network.add_edge(s, u, 1);
+                    network.add_edge(u, v, 1);
+                    network.add_edge(v, t, 1);
+                }
+            }
+            if g.get_vertices().is_empty() {
+                continue;
+            }
+
+            let res = hopcroft_karp(&g, None);
+            let flow = edmonds_karp(&network, &s, &t).value;
+            assert_eq!(flow as usize, res.matching.len());
+            check(&g, &res);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use crate::algorithms::bipartite::is_bipartite::{is_bipartite, Bipartiteness};
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Minimize,
+    Maximize,
+}
+
+/// `pairs` are (row, column) indexes of the matrix, or (left, right) vertices
+/// of the graph, sorted by their first element. The cost is summed in 64
+/// bits.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Assignment {
+    pub pairs: Vec<(usize, usize)>,
+    pub cost: i64,
+}
+
+pub fn run(costs: &[Vec<Weight>], objective: Objective) -> Assignment {
+    hungarian(costs, objective)
+}
+
+/// Optimal assignment of a rectangular cost matrix in O(n² m), with n the
+/// smaller dimension: every row gets a distinct column, or every column a
+/// distinct row when there are more rows.
+pub fn hungarian(costs: &[Vec<Weight>], objective: Objective) -> Assignment {
+    let rows = costs.len();
+    let columns = costs.first().map_or(0, |r| r.len());
+    if costs.iter().any(|r| r.len() != columns) {
+        panic!("Every row of the matrix must have the same length");
+    }
+
+    let sign = match objective {
+        Objective::Minimize => 1,
+        Objective::Maximize => -1,
+    };
+    let mut pairs = if rows <= columns {
+        solve(rows, columns, |i, j| sign * costs[i][j] as i64)
+    } else {
+        solve(columns, rows, |i, j| sign * costs[j][i] as i64)
+            .into_iter()
+            .map(|(j, i)| (i, j))
+            .collect()
+    };
+    pairs.sort();
+    let cost = pairs.iter().map(|(i, j)| costs[*i][*j] as i64).sum();
+
+    Assignment { pairs, cost }
+}
+
+/// Assignment on a complete bipartite graph. The left side is the one of the
+/// smallest vertex; pairs go from the left side to the right one.
+pub fn hungarian_graph(g: &dyn Graph<Vertex, Weight>, objective: Objective) -> Assignment {
+    if g.is_directed() {
+        panic!("The assignment needs an undirected graph");
+    }
+    let (mut left, mut right): (Vec<Vertex>, Vec<Vertex>) = match is_bipartite(g) {
+        Bipartiteness::Bipartite(left, right) => {
+            (left.into_iter().collect(), right.into_iter().collect())
+        }
+        Bipartiteness::OddCycle(_) => panic!("The graph is not bipartite"),
+    };
+    left.sort();
+    right.sort();
+
+    let costs: Vec<Vec<Weight>> = left
+        .iter()
+        .map(|u| {
+            right
+                .iter()
+                .map(|v| {
+                    *g.get_weight(u, v)
+                        .unwrap_or_else(|| panic!("The graph is not complete bipartite"))
+                })
+                .collect()
+        })
+        .collect();
+
+    let res = hungarian(&costs, objective);
+    Assignment {
+        pairs: res
+            .pairs
+            .iter()
+            .map(|(i, j)| (left[*i], right[*j]))
+            .collect(),
+        cost: res.cost,
+    }
+}
+
+/// Shortest augmenting paths with row and column potentials, one row at a
+/// time, for n <= m. Column 0 is a virtual column holding the row being
+/// inserted.
+fn solve(n: usize, m: usize, cost: impl Fn(usize, usize) -> i64) -> Vec<(usize, usize)> {
+    let mut u = vec![0i64; n + 1];
+    let mut v = vec![0i64; m + 1];
+    let mut row = vec![0usize; m + 1];
+    let mut way = vec![0usize; m + 1];
+
+    for i in 1..=n {
+        row[0] = i;
+        let mut j0 = 0;
+        let mut min = vec![i64::MAX; m + 1];
+        let mut used = vec![false; m + 1];
+
+        loop {
+            used[j0] = true;
+            let i0 = row[j0];
+            let mut delta = i64::MAX;
+            let mut j1 = 0;
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let reduced = cost(i0 - 1, j - 1) - u[i0] - v[j];
+                if reduced < min[j] {
+                    min[j] = reduced;
+                    way[j] = j0;
+                }
+                if min[j] < delta {
+                    delta = min[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min[j] -= delta;
+                }
+            }
+
+            j0 = j1;
+            if row[j0] == 0 {
+                break;
+            }
+        }
+
+        while j0 != 0 {
+            let j1 = way[j0];
+            row[j0] = row[j1];
+            j0 = j1;
+        }
+    }
+
+    (1..=m)
+        .filter(|j| row[*j] != 0)
+        .map(|j| (row[j] - 1, j - 1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{
+        families::{complete_bipartite, WeightPolicy},
+        rng::Rng,
+    };
+
+    fn brute_force(costs: &[Vec<Weight>], objective: Objective) -> i64 {
+        // Best signed total over the injective maps of the rows of `costs`
+        fn best(costs: &[Vec<i64>], i: usize, used: &mut [bool]) -> i64 {
+            if i == costs.len() {
+                return 0;
+            }
+            (0..used.len())
+                .filter(|j| !used[*j])
+                .collect::<Vec<usize>>()
+                .into_iter()
+                .map(|j| {
+                    used[j] = true;
+                    let total = costs[i][j] + best(costs, i + 1, used);
+                    used[j] = false;
+                    total
+                })
+                .max()
+                .unwrap()
+        }
+
+        let sign = match objective {
+            Objective::Minimize => -1,
+            Objective::Maximize => 1,
+        };
+        let (rows, columns) = (costs.len(), costs[0].len());
+        let signed: Vec<Vec<i64>> = if rows <= columns {
+            (0..rows)
+                .map(|i| (0..columns).map(|j| sign * costs[i][j] as i64).collect())
+                .collect()
+        } else {
+            (0..columns)
+                .map(|j| (0..rows).map(|i| sign * costs[i][j] as i64).collect())
+                .collect()
+        };
+
+        sign * best(&signed, 0, &mut vec![false; signed[0].len()])
+    }
+
+    #[test]
+    fn square_matrix() {
+        let costs = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+
+        let expected = Assignment {
+            pairs: vec![(0, 1), (1, 0), (2, 2)],
+            cost: 5,
+        };
+        assert_eq!(expected, hungarian(&costs, Objective::Minimize));
+    }
+
+    #[test]
+    fn maximize() {
+        let costs = vec![vec![4, 1, 3], vec![2, 0, 5], vec![3, 2, 2]];
+
+        let expected = Assignment {
+            pairs: vec![(0, 0), (1, 2), (2, 1)],
+            cost: 11,
+        };
+        assert_eq!(expected, hungarian(&costs, Objective::Maximize));
+    }
+
+    #[test]
+    fn more_columns_than_rows() {
+        let costs = vec![vec![7, 3, 9, 1], vec![2, 8, 1, 6]];
+
+        let expected = Assignment {
+            pairs: vec![(0, 3), (1, 2)],
+            cost: 2,
+        };
+        assert_eq!(expected, hungarian(&costs, Objective::Minimize));
+    }
+
+    #[test]
+    fn more_rows_than_columns() {
+        let costs = vec![vec![7, 2], vec![3, 8], vec![-1, 9], vec![1, 6]];
+
+        let expected = Assignment {
+            pairs: vec![(0, 1), (2, 0)],
+            cost: 1,
+        };
+        assert_eq!(expected, hungarian(&costs, Objective::Minimize));
+    }
+
+    #[test]
+    fn empty_matrix() {
+        let expected = Assignment {
+            pairs: vec![],
+            cost: 0,
+        };
+        assert_eq!(expected, hungarian(&[], Objective::Minimize));
+    }
+
+    #[test]
+    #[should_panic(expected = "Every row of the matrix must have the same length")]
+    fn ragged_matrix() {
+        hungarian(&[vec![1, 2], vec![3]], Objective::Minimize);
+    }
+
+    #[test]
+    fn random_matrices() {
+        let mut rng = Rng::new(48);
+
+        for round in 0..300 {
+            let (rows, columns) = (1 + rng.below(6), 1 + rng.below(6));
+            let costs: Vec<Vec<Weight>> = (0..rows)
+                .map(|_| (0..columns).map(|_| rng.in_range(&(-20..=20))).collect())
+                .collect();
+            let objective = if round % 2 == 0 {
+                Objective::Minimize
+            } else {
+                Objective::Maximize
+            };
+
+            let res = hungarian(&costs, objective);
+            assert_eq!(rows.min(columns), res.pairs.len());
+            let mut rows_used: Vec<usize> = res.pairs.iter().map(|p| p.0).collect();
+            let mut columns_used: Vec<usize> = res.pairs.iter().map(|p| p.1).collect();
+            rows_used.dedup();
+            columns_used.sort();
+            columns_used.dedup();
+            assert_eq!(res.pairs.len(), rows_used.len());
+            assert_eq!(res.pairs.len(), columns_used.len());
+            assert_eq!(brute_force(&costs, objective), res.cost);
+        }
+    }
+
+    #[test]
+    fn complete_bipartite_graph() {
+        let policy = WeightPolicy::Random {
+            seed: 48,
+            range: -50..=50,
+        };
+        let g = complete_bipartite(3, 4, &policy);
+
+        let res = hungarian_graph(&g, Objective::Minimize);
+        let costs: Vec<Vec<Weight>> = (1..=3)
+            .map(|u| (4..=7).map(|v| *g.get_weight(&u, &v).unwrap()).collect())
+            .collect();
+        assert_eq!(hungarian(&costs, Objective::Minimize).cost, res.cost);
+        assert_eq!(3, res.pairs.len());
+        for (u, v) in &res.pairs {
+            assert!((1..=3).contains(u) && (4..=7).contains(v));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The graph is not complete bipartite")]
+    fn missing_edge() {
+        let mut g = complete_bipartite(2, 2, &WeightPolicy::Unit);
+        g.delete_edge(&1, &3);
+
+        hungarian_graph(&g, Objective::Minimize);
+    }
+}
@@ -0,0 +1,74 @@
+use std::collections::HashSet;
+
+use crate::algorithms::matching::weighted_matching::Matching;
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, matching: &Matching) -> bool {
+    is_matching(g, matching)
+}
+
+/// Every edge belongs to the graph with the same weight and no two edges
+/// share an endpoint.
+pub fn is_matching(g: &dyn Graph<Vertex, Weight>, matching: &Matching) -> bool {
+    let mut matched = HashSet::new();
+
+    matching.iter().all(|(u, v, w)| {
+        g.get_weight(u, v) == Some(w) && u != v && matched.insert(*u) && matched.insert(*v)
+    })
+}
+
+pub fn is_perfect_matching(g: &dyn Graph<Vertex, Weight>, matching: &Matching) -> bool {
+    is_matching(g, matching) && 2 * matching.len() == g.get_vertices().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    fn square() -> UndirectedGraph<Vertex, Weight> {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, 3);
+        g.add_edge(4, 1, 4);
+
+        g
+    }
+
+    #[test]
+    fn perfect_matching() {
+        let g = square();
+
+        assert!(is_matching(&g, &vec![(1, 2, 1), (3, 4, 3)]));
+        assert!(is_perfect_matching(&g, &vec![(1, 2, 1), (3, 4, 3)]));
+    }
+
+    #[test]
+    fn not_perfect() {
+        let g = square();
+
+        assert!(is_matching(&g, &vec![(2, 3, 2)]));
+        assert!(!is_perfect_matching(&g, &vec![(2, 3, 2)]));
+        assert!(is_matching(&g, &vec![]));
+    }
+
+    #[test]
+    fn shared_endpoint() {
+        let g = square();
+
+        assert!(!is_matching(&g, &vec![(1, 2, 1), (2, 3, 2)]));
+    }
+
+    #[test]
+    fn missing_edge_or_wrong_weight() {
+        let g = square();
+
+        assert!(!is_matching(&g, &vec![(1, 3, 1)]));
+        assert!(!is_matching(&g, &vec![(1, 2, 5)]));
+    }
+}
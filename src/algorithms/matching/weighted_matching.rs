@@ -0,0 +1,817 @@
+use std::collections::HashMap;
+
+use crate::graph::{
+    graph::{Edge, Graph},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub type Matching = Vec<Edge<Vertex, Weight>>;
+
+const NONE: usize = usize::MAX;
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Matching {
+    max_weight_matching(g, false)
+}
+
+/// Matching of maximum total weight, found with Edmonds' weighted blossom
+/// algorithm in O(n³). With `max_cardinality` the heaviest matching among the
+/// ones of maximum size is returned instead. Edges are listed with their
+/// endpoints sorted.
+pub fn max_weight_matching(g: &dyn Graph<Vertex, Weight>, max_cardinality: bool) -> Matching {
+    blossom(g, |w| w as i64, max_cardinality)
+}
+
+/// Perfect matching of minimum total weight, if the graph has a perfect
+/// matching at all.
+pub fn min_weight_perfect_matching(g: &dyn Graph<Vertex, Weight>) -> Option<Matching> {
+    let max = g.get_edges().iter().map(|e| e.2 as i64).max().unwrap_or(0);
+    let matching = blossom(g, |w| max + 1 - w as i64, true);
+
+    if 2 * matching.len() == g.get_vertices().len() {
+        Some(matching)
+    } else {
+        None
+    }
+}
+
+fn blossom(
+    g: &dyn Graph<Vertex, Weight>,
+    weight: impl Fn(Weight) -> i64,
+    max_cardinality: bool,
+) -> Matching {
+    let mut vertices: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+    vertices.sort();
+    let indexes: HashMap<Vertex, usize> =
+        vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+    let mut edges: Vec<Edge<Vertex, Weight>> = g
+        .get_edges()
+        .iter()
+        .filter(|(u, v, _)| u != v)
+        .map(|(u, v, w)| (*u.min(v), *u.max(v), *w))
+        .collect();
+    edges.sort();
+    edges.dedup_by_key(|(u, v, _)| (*u, *v));
+
+    let mut search = WeightedBlossom::new(
+        vertices.len(),
+        edges
+            .iter()
+            .map(|(u, v, w)| (indexes[u], indexes[v], weight(*w)))
+            .collect(),
+    );
+    search.solve(max_cardinality);
+
+    let mut matching: Matching = (0..vertices.len())
+        .filter(|v| search.mate[*v] != NONE && search.mate[*v] % 2 == 1)
+        .map(|v| edges[search.mate[v] / 2])
+        .collect();
+    matching.sort();
+
+    matching
+}
+
+/// Port of the primal-dual method of Galil, "Efficient algorithms for finding
+/// maximum matching in graphs". Vertices are 0..n and blossoms n..2n; edge k
+/// has the endpoints 2k and 2k + 1, so `p ^ 1` is the other end of `p`.
+struct WeightedBlossom {
+    n: usize,
+    edges: Vec<(usize, usize, i64)>,
+    endpoint: Vec<usize>,
+    neighbour_ends: Vec<Vec<usize>>,
+    mate: Vec<usize>,
+    label: Vec<u8>,
+    label_end: Vec<usize>,
+    in_blossom: Vec<usize>,
+    blossom_parent: Vec<usize>,
+    blossom_children: Vec<Vec<usize>>,
+    blossom_base: Vec<usize>,
+    blossom_endpoints: Vec<Vec<usize>>,
+    best_edge: Vec<usize>,
+    blossom_best_edges: Vec<Option<Vec<usize>>>,
+    unused_blossoms: Vec<usize>,
+    dual: Vec<i64>,
+    allowed: Vec<bool>,
+    queue: Vec<usize>,
+}
+
+fn at(j: isize, len: usize) -> usize {
+    j.rem_euclid(len as isize) as usize
+}
+
+impl WeightedBlossom {
+    fn new(n: usize, edges: Vec<(usize, usize, i64)>) -> WeightedBlossom {
+        let max_weight = edges.iter().map(|e| e.2).max().unwrap_or(0).max(0);
+
+        let mut neighbour_ends = vec![vec![]; n];
+        for (k, (i, j, _)) in edges.iter().enumerate() {
+            neighbour_ends[*i].push(2 * k + 1);
+            neighbour_ends[*j].push(2 * k);
+        }
+
+        WeightedBlossom {
+            n,
+            endpoint: (0..2 * edges.len())
+                .map(|p| {
+                    if p % 2 == 0 {
+                        edges[p / 2].0
+                    } else {
+                        edges[p / 2].1
+                    }
+                })
+                .collect(),
+            neighbour_ends,
+            mate: vec![NONE; n],
+            label: vec![0; 2 * n],
+            label_end: vec![NONE; 2 * n],
+            in_blossom: (0..n).collect(),
+            blossom_parent: vec![NONE; 2 * n],
+            blossom_children: vec![vec![]; 2 * n],
+            blossom_base: (0..n).chain(std::iter::repeat_n(NONE, n)).collect(),
+            blossom_endpoints: vec![vec![]; 2 * n],
+            best_edge: vec![NONE; 2 * n],
+            blossom_best_edges: vec![None; 2 * n],
+            unused_blossoms: (n..2 * n).collect(),
+            dual: std::iter::repeat_n(max_weight, n)
+                .chain(std::iter::repeat_n(0, n))
+                .collect(),
+            allowed: vec![false; edges.len()],
+            queue: vec![],
+            edges,
+        }
+    }
+
+    fn slack(&self, k: usize) -> i64 {
+        let (i, j, w) = self.edges[k];
+        self.dual[i] + self.dual[j] - 2 * w
+    }
+
+    fn blossom_leaves(&self, b: usize) -> Vec<usize> {
+        let mut leaves = vec![];
+        let mut stack = vec![b];
+
+        while let Some(t) = stack.pop() {
+            if t < self.n {
+                leaves.push(t);
+            } else {
+                stack.extend(self.blossom_children[t].iter().rev());
+            }
+        }
+
+        leaves
+    }
+
+    fn assign_label(&mut self, w: usize, t: u8, p: usize) {
+        let b = self.in_blossom[w];
+        self.label[w] = t;
+        self.label[b] = t;
+        self.label_end[w] = p;
+        self.label_end[b] = p;
+        self.best_edge[w] = NONE;
+        self.best_edge[b] = NONE;
+
+        if t == 1 {
+            let leaves = self.blossom_leaves(b);
+            self.queue.extend(leaves);
+        } else {
+            let m = self.mate[self.blossom_base[b]];
+            self.assign_label(self.endpoint[m], 1, m ^ 1);
+        }
+    }
+
+    /// Traces back from `v` and `w` to find either a new blossom, whose base
+    /// is returned, or an augmenting path, in which case NONE is returned.
+    fn scan_blossom(&mut self, mut v: usize, mut w: usize) -> usize {
+        let mut path = vec![];
+        let mut base = NONE;
+
+        while v != NONE || w != NONE {
+            let mut b = self.in_blossom[v];
+            if self.label[b] & 4 != 0 {
+                base = self.blossom_base[b];
+                break;
+            }
+
+            path.push(b);
+            self.label[b] = 5;
+            if self.label_end[b] == NONE {
+                v = NONE;
+            } else {
+                v = self.endpoint[self.label_end[b]];
+                b = self.in_blossom[v];
+                v = self.endpoint[self.label_end[b]];
+            }
+            if w != NONE {
+                std::mem::swap(&mut v, &mut w);
+            }
+        }
+
+        for b in path {
+            self.label[b] = 1;
+        }
+
+        base
+    }
+
+    fn add_blossom(&mut self, base: usize, k: usize) {
+        let (v, w, _) = self.edges[k];
+        let bb = self.in_blossom[base];
+        let mut bv = self.in_blossom[v];
+        let mut bw = self.in_blossom[w];
+
+        let b = self.unused_blossoms.pop().unwrap();
+        self.blossom_base[b] = base;
+        self.blossom_parent[b] = NONE;
+        self.blossom_parent[bb] = b;
+
+        let mut path = vec![];
+        let mut endpoints = vec![];
+        while bv != bb {
+            self.blossom_parent[bv] = b;
+            path.push(bv);
+            endpoints.push(self.label_end[bv]);
+            bv = self.in_blossom[self.endpoint[self.label_end[bv]]];
+        }
+        path.push(bb);
+        path.reverse();
+        endpoints.reverse();
+        endpoints.push(2 * k);
+        while bw != bb {
+            self.blossom_parent[bw] = b;
+            path.push(bw);
+            endpoints.push(self.label_end[bw] ^ 1);
+            bw = self.in_blossom[self.endpoint[self.label_end[bw]]];
+        }
+
+        self.blossom_children[b] = path.clone();
+        self.blossom_endpoints[b] = endpoints;
+        self.label[b] = 1;
+        self.label_end[b] = self.label_end[bb];
+        self.dual[b] = 0;
+
+        for v in self.blossom_leaves(b) {
+            if self.label[self.in_blossom[v]] == 2 {
+                self.queue.push(v);
+            }
+            self.in_blossom[v] = b;
+        }
+
+        let mut best_edge_to = vec![NONE; 2 * self.n];
+        for bv in path {
+            let lists: Vec<Vec<usize>> = match self.blossom_best_edges[bv].take() {
+                Some(list) => vec![list],
+                None => self
+                    .blossom_leaves(bv)
+                    .iter()
+                    .map(|v| self.neighbour_ends[*v].iter().map(|p| p / 2).collect())
+                    .collect(),
+            };
+
+            for k in lists.into_iter().flatten() {
+                let (i, j, _) = self.edges[k];
+                let j = if self.in_blossom[j] == b { i } else { j };
+                let bj = self.in_blossom[j];
+                if bj != b
+                    && self.label[bj] == 1
+                    && (best_edge_to[bj] == NONE || self.slack(k) < self.slack(best_edge_to[bj]))
+                {
+                    best_edge_to[bj] = k;
+                }
+            }
+            self.best_edge[bv] = NONE;
+        }
+
+        let best_edges: Vec<usize> = best_edge_to.into_iter().filter(|k| *k != NONE).collect();
+        self.best_edge[b] = NONE;
+        for k in &best_edges {
+            if self.best_edge[b] == NONE || self.slack(*k) < self.slack(self.best_edge[b]) {
+                self.best_edge[b] = *k;
+            }
+        }
+        self.blossom_best_edges[b] = Some(best_edges);
+    }
+
+    fn expand_blossom(&mut self, b: usize, end_stage: bool) {
+        let children = self.blossom_children[b].clone();
+        let len = children.len();
+
+        for s in children.iter().copied() {
+            self.blossom_parent[s] = NONE;
+            if s < self.n {
+                self.in_blossom[s] = s;
+            } else if end_stage && self.dual[s] == 0 {
+                self.expand_blossom(s, end_stage);
+            } else {
+                for v in self.blossom_leaves(s) {
+                    self.in_blossom[v] = s;
+                }
+            }
+        }
+
+        if !end_stage && self.label[b] == 2 {
+            // Relabel the children on the even-length path from the entry
+            // child to the base; the others become free.
+            let entry_child = self.in_blossom[self.endpoint[self.label_end[b] ^ 1]];
+            let mut j = children.iter().position(|c| *c == entry_child).unwrap() as isize;
+            let (step, trick) = if j & 1 == 1 {
+                j -= len as isize;
+                (1, 0)
+            } else {
+                (-1, 1)
+            };
+
+            let mut p = self.label_end[b];
+            while j != 0 {
+                let q = self.blossom_endpoints[b][at(j - trick as isize, len)];
+                self.label[self.endpoint[p ^ 1]] = 0;
+                self.label[self.endpoint[q ^ trick ^ 1]] = 0;
+                self.assign_label(self.endpoint[p ^ 1], 2, p);
+                self.allowed[q / 2] = true;
+                j += step;
+                p = self.blossom_endpoints[b][at(j - trick as isize, len)] ^ trick;
+                self.allowed[p / 2] = true;
+                j += step;
+            }
+
+            let bv = children[at(j, len)];
+            self.label[self.endpoint[p ^ 1]] = 2;
+            self.label[bv] = 2;
+            self.label_end[self.endpoint[p ^ 1]] = p;
+            self.label_end[bv] = p;
+            self.best_edge[bv] = NONE;
+            j += step;
+
+            while children[at(j, len)] != entry_child {
+                let bv = children[at(j, len)];
+                j += step;
+                if self.label[bv] == 1 {
+                    continue;
+                }
+
+                let labeled = self
+                    .blossom_leaves(bv)
+                    .into_iter()
+                    .find(|v| self.label[*v] != 0);
+                if let Some(v) = labeled {
+                    self.label[v] = 0;
+                    self.label[self.endpoint[self.mate[self.blossom_base[bv]]]] = 0;
+                    self.assign_label(v, 2, self.label_end[v]);
+                }
+            }
+        }
+
+        self.label[b] = 0;
+        self.label_end[b] = NONE;
+        self.blossom_children[b].clear();
+        self.blossom_endpoints[b].clear();
+        self.blossom_base[b] = NONE;
+        self.blossom_best_edges[b] = None;
+        self.best_edge[b] = NONE;
+        self.unused_blossoms.push(b);
+    }
+
+    /// Swaps matched and unmatched edges on the path from `v` to the base of
+    /// blossom `b`, so that `v` becomes its new base.
+    fn augment_blossom(&mut self, b: usize, v: usize) {
+        let mut t = v;
+        while self.blossom_parent[t] != b {
+            t = self.blossom_parent[t];
+        }
+        if t >= self.n {
+            self.augment_blossom(t, v);
+        }
+
+        let len = self.blossom_children[b].len();
+        let i = self.blossom_children[b]
+            .iter()
+            .position(|c| *c == t)
+            .unwrap();
+        let mut j = i as isize;
+        let (step, trick) = if i & 1 == 1 {
+            j -= len as isize;
+            (1, 0)
+        } else {
+            (-1, 1)
+        };
+
+        while j != 0 {
+            j += step;
+            let t = self.blossom_children[b][at(j, len)];
+            let p = self.blossom_endpoints[b][at(j - trick as isize, len)] ^ trick;
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p]);
+            }
+            j += step;
+            let t = self.blossom_children[b][at(j, len)];
+            if t >= self.n {
+                self.augment_blossom(t, self.endpoint[p ^ 1]);
+            }
+            self.mate[self.endpoint[p]] = p ^ 1;
+            self.mate[self.endpoint[p ^ 1]] = p;
+        }
+
+        self.blossom_children[b].rotate_left(i);
+        self.blossom_endpoints[b].rotate_left(i);
+        self.blossom_base[b] = self.blossom_base[self.blossom_children[b][0]];
+    }
+
+    fn augment_matching(&mut self, k: usize) {
+        let (v, w, _) = self.edges[k];
+
+        for (mut s, mut p) in [(v, 2 * k + 1), (w, 2 * k)] {
+            loop {
+                let bs = self.in_blossom[s];
+                if bs >= self.n {
+                    self.augment_blossom(bs, s);
+                }
+                self.mate[s] = p;
+                if self.label_end[bs] == NONE {
+                    break;
+                }
+
+                let bt = self.in_blossom[self.endpoint[self.label_end[bs]]];
+                s = self.endpoint[self.label_end[bt]];
+                let j = self.endpoint[self.label_end[bt] ^ 1];
+                if bt >= self.n {
+                    self.augment_blossom(bt, j);
+                }
+                self.mate[j] = self.label_end[bt];
+                p = self.label_end[bt] ^ 1;
+            }
+        }
+    }
+
+    /// Grows alternating trees from the free vertices until an augmenting
+    /// path is found, then updates the duals; each stage augments once.
+    fn solve(&mut self, max_cardinality: bool) {
+        let n = self.n;
+
+        for _ in 0..n {
+            self.label.fill(0);
+            self.best_edge.fill(NONE);
+            self.blossom_best_edges[n..].fill(None);
+            self.allowed.fill(false);
+            self.queue.clear();
+
+            for v in 0..n {
+                if self.mate[v] == NONE && self.label[self.in_blossom[v]] == 0 {
+                    self.assign_label(v, 1, NONE);
+                }
+            }
+
+            let mut augmented = false;
+            loop {
+                while !augmented {
+                    let Some(v) = self.queue.pop() else {
+                        break;
+                    };
+
+                    for p in self.neighbour_ends[v].clone() {
+                        let k = p / 2;
+                        let w = self.endpoint[p];
+                        if self.in_blossom[v] == self.in_blossom[w] {
+                            continue;
+                        }
+
+                        let slack = self.slack(k);
+                        if !self.allowed[k] && slack <= 0 {
+                            self.allowed[k] = true;
+                        }
+
+                        if self.allowed[k] {
+                            if self.label[self.in_blossom[w]] == 0 {
+                                self.assign_label(w, 2, p ^ 1);
+                            } else if self.label[self.in_blossom[w]] == 1 {
+                                let base = self.scan_blossom(v, w);
+                                if base != NONE {
+                                    self.add_blossom(base, k);
+                                } else {
+                                    self.augment_matching(k);
+                                    augmented = true;
+                                    break;
+                                }
+                            } else if self.label[w] == 0 {
+                                self.label[w] = 2;
+                                self.label_end[w] = p ^ 1;
+                            }
+                        } else if self.label[self.in_blossom[w]] == 1 {
+                            let b = self.in_blossom[v];
+                            if self.best_edge[b] == NONE || slack < self.slack(self.best_edge[b]) {
+                                self.best_edge[b] = k;
+                            }
+                        } else if self.label[w] == 0
+                            && (self.best_edge[w] == NONE || slack < self.slack(self.best_edge[w]))
+                        {
+                            self.best_edge[w] = k;
+                        }
+                    }
+                }
+
+                if augmented {
+                    break;
+                }
+
+                // No augmenting path with the current duals: find the largest
+                // dual change that keeps them feasible.
+                let mut delta_type = 0;
+                let mut delta = 0;
+                let mut delta_edge = NONE;
+                let mut delta_blossom = NONE;
+
+                if !max_cardinality {
+                    delta_type = 1;
+                    delta = *self.dual[..n].iter().min().unwrap();
+                }
+                for v in 0..n {
+                    if self.label[self.in_blossom[v]] == 0 && self.best_edge[v] != NONE {
+                        let d = self.slack(self.best_edge[v]);
+                        if delta_type == 0 || d < delta {
+                            delta = d;
+                            delta_type = 2;
+                            delta_edge = self.best_edge[v];
+                        }
+                    }
+                }
+                for b in 0..2 * n {
+                    if self.blossom_parent[b] == NONE
+                        && self.label[b] == 1
+                        && self.best_edge[b] != NONE
+                    {
+                        let d = self.slack(self.best_edge[b]) / 2;
+                        if delta_type == 0 || d < delta {
+                            delta = d;
+                            delta_type = 3;
+                            delta_edge = self.best_edge[b];
+                        }
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossom_base[b] != NONE
+                        && self.blossom_parent[b] == NONE
+                        && self.label[b] == 2
+                        && (delta_type == 0 || self.dual[b] < delta)
+                    {
+                        delta = self.dual[b];
+                        delta_type = 4;
+                        delta_blossom = b;
+                    }
+                }
+                if delta_type == 0 {
+                    delta_type = 1;
+                    delta = self.dual[..n].iter().min().unwrap().max(&0).to_owned();
+                }
+
+                for v in 0..n {
+                    match self.label[self.in_blossom[v]] {
+                        1 => self.dual[v] -= delta,
+                        2 => self.dual[v] += delta,
+                        _ => {}
+                    }
+                }
+                for b in n..2 * n {
+                    if self.blossom_base[b] != NONE && self.blossom_parent[b] == NONE {
+                        match self.label[b] {
+                            1 => self.dual[b] += delta,
+                            2 => self.dual[b] -= delta,
+                            _ => {}
+                        }
+                    }
+                }
+
+                match delta_type {
+                    1 => break,
+                    2 => {
+                        self.allowed[delta_edge] = true;
+                        let (i, j, _) = self.edges[delta_edge];
+                        let i = if self.label[self.in_blossom[i]] == 0 {
+                            j
+                        } else {
+                            i
+                        };
+                        self.queue.push(i);
+                    }
+                    3 => {
+                        self.allowed[delta_edge] = true;
+                        self.queue.push(self.edges[delta_edge].0);
+                    }
+                    _ => self.expand_blossom(delta_blossom, false),
+                }
+            }
+
+            if !augmented {
+                break;
+            }
+
+            for b in n..2 * n {
+                if self.blossom_parent[b] == NONE
+                    && self.blossom_base[b] != NONE
+                    && self.label[b] == 1
+                    && self.dual[b] == 0
+                {
+                    self.expand_blossom(b, true);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::matching::is_matching::{is_matching, is_perfect_matching};
+    use crate::graph::generators::{random::gnp, rng::Rng};
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    fn weight(matching: &Matching) -> Weight {
+        matching.iter().map(|e| e.2).sum()
+    }
+
+    fn brute_force(edges: &[Edge<Vertex, Weight>], max_cardinality: bool) -> (usize, Weight) {
+        let mut best = (0, 0);
+
+        for mask in 0..1u32 << edges.len() {
+            let chosen: Vec<&Edge<Vertex, Weight>> = (0..edges.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| &edges[i])
+                .collect();
+            let mut ends: Vec<Vertex> = chosen.iter().flat_map(|e| [e.0, e.1]).collect();
+            ends.sort();
+            ends.dedup();
+            if ends.len() != 2 * chosen.len() {
+                continue;
+            }
+
+            let weight = chosen.iter().map(|e| e.2).sum();
+            let key = if max_cardinality { chosen.len() } else { 0 };
+            if (key, weight) > best {
+                best = (key, weight);
+            }
+        }
+
+        best
+    }
+
+    #[test]
+    fn max_weight_matching_path() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 5);
+        g.add_edge(2, 3, 11);
+        g.add_edge(3, 4, 5);
+
+        assert_eq!(vec![(2, 3, 11)], max_weight_matching(&g, false));
+        assert_eq!(vec![(1, 2, 5), (3, 4, 5)], max_weight_matching(&g, true));
+    }
+
+    #[test]
+    fn max_weight_matching_ignores_negative_edges() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, -3);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, -1);
+
+        assert_eq!(vec![(2, 3, 2)], max_weight_matching(&g, false));
+        assert_eq!(vec![(1, 2, -3), (3, 4, -1)], max_weight_matching(&g, true));
+    }
+
+    #[test]
+    fn max_weight_matching_with_blossom() {
+        // Triangle 1-2-3 with pendant edges: the optimum must go through the
+        // odd cycle
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 9);
+        g.add_edge(1, 3, 9);
+        g.add_edge(2, 3, 10);
+        g.add_edge(2, 4, 8);
+        g.add_edge(3, 5, 8);
+        g.add_edge(1, 6, 5);
+        g.add_edge(4, 5, 1);
+
+        let current = max_weight_matching(&g, false);
+        assert!(is_matching(&g, &current));
+        assert_eq!(21, weight(&current));
+    }
+
+    #[test]
+    fn max_weight_matching_matches_brute_force() {
+        let mut rng = Rng::new(3);
+        for round in 0..40 {
+            let g = gnp(8, 0.25, &(-5..=15), &mut rng);
+            let edges: Vec<Edge<Vertex, Weight>> = g.get_edges().iter().copied().collect();
+            if edges.len() > 16 {
+                continue;
+            }
+            let max_cardinality = round % 2 == 0;
+
+            let current = max_weight_matching(&g, max_cardinality);
+            assert!(is_matching(&g, &current));
+            let key = if max_cardinality { current.len() } else { 0 };
+            assert_eq!(
+                brute_force(&edges, max_cardinality),
+                (key, weight(&current))
+            );
+        }
+    }
+
+    #[test]
+    fn min_weight_perfect_matching_complete_graph() {
+        let mut g = UndirectedGraph::new();
+        let points: [(i32, i32); 6] = [(0, 0), (1, 0), (5, 5), (5, 6), (9, 0), (9, 2)];
+
+        for u in 0..points.len() {
+            for v in u + 1..points.len() {
+                let (a, b) = (points[u], points[v]);
+                g.add_edge(u, v, (a.0 - b.0).abs() + (a.1 - b.1).abs());
+            }
+        }
+
+        let expected = vec![(0, 1, 1), (2, 3, 1), (4, 5, 2)];
+        let current = min_weight_perfect_matching(&g).unwrap();
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn min_weight_perfect_matching_prefers_perfect() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(1, 4, 100);
+        g.add_edge(2, 4, 100);
+
+        let expected = vec![(1, 2, 1), (3, 4, 1)];
+        let current = min_weight_perfect_matching(&g).unwrap();
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn min_weight_perfect_matching_with_blossom() {
+        // Vertex 6 forces 1-6, after which the cheap triangle edge 2-3 loses
+        // to the pendant pair 2-4, 3-5
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(2, 4, 3);
+        g.add_edge(3, 5, 3);
+        g.add_edge(1, 6, 6);
+        g.add_edge(4, 5, 9);
+
+        let current = min_weight_perfect_matching(&g).unwrap();
+        assert!(is_perfect_matching(&g, &current));
+        assert_eq!(12, weight(&current));
+    }
+
+    #[test]
+    fn min_weight_perfect_matching_none() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(1, 4, 1);
+
+        assert_eq!(None, min_weight_perfect_matching(&g));
+    }
+
+    #[test]
+    fn min_weight_perfect_matching_negative_weights() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, -5);
+        g.add_edge(2, 3, -20);
+        g.add_edge(3, 4, -5);
+        g.add_edge(1, 4, 3);
+        g.add_edge(1, 3, -1);
+
+        let current = min_weight_perfect_matching(&g).unwrap();
+        assert!(is_perfect_matching(&g, &current));
+        assert_eq!(-17, weight(&current));
+    }
+
+    #[test]
+    fn min_weight_perfect_matching_matches_brute_force() {
+        let mut rng = Rng::new(47);
+        for _ in 0..40 {
+            let g = gnp(6, 0.5, &(-20..=20), &mut rng);
+            let edges: Vec<Edge<Vertex, Weight>> = g.get_edges().iter().copied().collect();
+
+            // Maximising the negated weights with maximum cardinality
+            let negated: Vec<Edge<Vertex, Weight>> =
+                edges.iter().map(|(u, v, w)| (*u, *v, -w)).collect();
+            let (size, best) = brute_force(&negated, true);
+
+            match min_weight_perfect_matching(&g) {
+                Some(current) => {
+                    assert!(is_perfect_matching(&g, &current));
+                    assert_eq!(g.get_vertices().len(), 2 * size);
+                    assert_eq!(-best, weight(&current));
+                }
+                None => assert!(2 * size < g.get_vertices().len()),
+            }
+        }
+    }
+}
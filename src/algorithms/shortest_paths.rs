@@ -0,0 +1,4 @@
+pub mod all_pairs;
+pub mod astar;
+pub mod bellman_ford;
+pub mod dijkstra;
@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+
+use crate::algorithms::shortest_paths::bellman_ford::{potentials, BellmanFord};
+use crate::algorithms::shortest_paths::dijkstra::dijkstra;
+use crate::graph::{
+    directed_graph::DirectedGraph,
+    graph::{Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+/// Shortest distances between every ordered pair of vertices. Rows and
+/// columns follow `vertices`, which is sorted; `predecessors[i][j]` is the
+/// index of the vertex before `vertices[j]` on a shortest path from
+/// `vertices[i]`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DistanceMatrix {
+    pub vertices: Vec<Vertex>,
+    pub distances: Vec<Vec<Option<Weight>>>,
+    predecessors: Vec<Vec<Option<usize>>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AllPairs {
+    Distances(DistanceMatrix),
+    NegativeCycle(Path<Vertex, Weight>),
+}
+
+impl DistanceMatrix {
+    fn new(g: &dyn Graph<Vertex, Weight>) -> DistanceMatrix {
+        let mut vertices: Vec<Vertex> = g.get_vertices().iter().copied().collect();
+        vertices.sort();
+        let n = vertices.len();
+
+        DistanceMatrix {
+            vertices,
+            distances: vec![vec![None; n]; n],
+            predecessors: vec![vec![None; n]; n],
+        }
+    }
+
+    fn index(&self, v: &Vertex) -> Option<usize> {
+        self.vertices.binary_search(v).ok()
+    }
+
+    pub fn distance(&self, u: &Vertex, v: &Vertex) -> Option<Weight> {
+        self.distances[self.index(u)?][self.index(v)?]
+    }
+
+    /// A shortest path from `u` to `v`, empty when they are the same vertex.
+    pub fn path(&self, u: &Vertex, v: &Vertex) -> Option<Path<Vertex, Weight>> {
+        let (i, mut j) = (self.index(u)?, self.index(v)?);
+        self.distances[i][j]?;
+
+        let mut path = vec![];
+        while j != i {
+            let p = self.predecessors[i][j].unwrap();
+            // Subpaths of shortest paths are shortest
+            let w = self.distances[i][j].unwrap() - self.distances[i][p].unwrap();
+            path.push((self.vertices[p], self.vertices[j], w));
+            j = p;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// One row per source with its distances, empty where the target is
+    /// unreachable. The header row lists the targets.
+    pub fn to_csv(&self) -> String {
+        let mut out = String::new();
+
+        for v in &self.vertices {
+            out += &format!(",{}", v);
+        }
+        out += "\n";
+        for (u, row) in self.vertices.iter().zip(&self.distances) {
+            out += &u.to_string();
+            for d in row {
+                out += ",";
+                if let Some(d) = d {
+                    out += &d.to_string();
+                }
+            }
+            out += "\n";
+        }
+
+        out
+    }
+}
+
+pub fn run_floyd_warshall(g: &dyn Graph<Vertex, Weight>) -> AllPairs {
+    floyd_warshall(g)
+}
+
+pub fn run_johnson(g: &dyn Graph<Vertex, Weight>) -> AllPairs {
+    johnson(g)
+}
+
+/// O(n^3), suited to dense graphs. On a negative cycle the cycle is found
+/// again with Bellman-Ford to return it.
+pub fn floyd_warshall(g: &dyn Graph<Vertex, Weight>) -> AllPairs {
+    let mut m = DistanceMatrix::new(g);
+    let n = m.vertices.len();
+
+    for i in 0..n {
+        m.distances[i][i] = Some(0);
+    }
+    for (u, v, w) in g.get_edges() {
+        let (i, j) = (m.index(u).unwrap(), m.index(v).unwrap());
+        let arcs = if g.is_directed() {
+            vec![(i, j)]
+        } else {
+            vec![(i, j), (j, i)]
+        };
+        for (i, j) in arcs {
+            if i == j {
+                if *w < 0 {
+                    m.distances[i][i] = Some(*w);
+                }
+            } else {
+                m.distances[i][j] = Some(*w);
+                m.predecessors[i][j] = Some(i);
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let Some(dik) = m.distances[i][k] else {
+                continue;
+            };
+            for j in 0..n {
+                if let Some(dkj) = m.distances[k][j] {
+                    let d = dik.saturating_add(dkj);
+                    if m.distances[i][j].is_none_or(|dij| d < dij) {
+                        m.distances[i][j] = Some(d);
+                        m.predecessors[i][j] = m.predecessors[k][j];
+                    }
+                }
+            }
+        }
+
+        // Stop before distances around the cycle keep shrinking
+        if (0..n).any(|i| m.distances[i][i].unwrap() < 0) {
+            return match potentials(g) {
+                BellmanFord::NegativeCycle(cycle) => AllPairs::NegativeCycle(cycle),
+                BellmanFord::Distances(_, _) => unreachable!(),
+            };
+        }
+    }
+
+    AllPairs::Distances(m)
+}
+
+/// Reweights the edges with Bellman-Ford potentials so that they are
+/// non-negative, then runs Dijkstra from every vertex. O(nm log n), suited
+/// to sparse graphs with negative weights.
+pub fn johnson(g: &dyn Graph<Vertex, Weight>) -> AllPairs {
+    let h = match potentials(g) {
+        BellmanFord::NegativeCycle(cycle) => return AllPairs::NegativeCycle(cycle),
+        BellmanFord::Distances(h, _) => h,
+    };
+
+    // Without a negative cycle an undirected graph has no negative edge, so
+    // every potential is 0
+    let mut directed = DirectedGraph::new();
+    let reweighted: &dyn Graph<Vertex, Weight> = if g.is_directed() {
+        for (u, v, w) in g.get_edges() {
+            directed.add_edge(*u, *v, w + h[u] - h[v]);
+        }
+        &directed
+    } else {
+        g
+    };
+
+    let mut m = DistanceMatrix::new(g);
+    let index: HashMap<Vertex, usize> = m
+        .vertices
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (*v, i))
+        .collect();
+
+    for (i, u) in m.vertices.clone().iter().enumerate() {
+        let (distances, parents) = dijkstra(reweighted, u);
+
+        for (v, d) in distances {
+            let j = index[&v];
+            m.distances[i][j] = Some(d - h[u] + h[&v]);
+            m.predecessors[i][j] = parents.get(&v).map(|p| index[p]);
+        }
+    }
+
+    AllPairs::Distances(m)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{generators::rng::Rng, undirected_graph::UndirectedGraph};
+
+    fn distances(res: &AllPairs) -> &DistanceMatrix {
+        match res {
+            AllPairs::Distances(m) => m,
+            AllPairs::NegativeCycle(_) => panic!("No negative cycle expected"),
+        }
+    }
+
+    fn check_paths(g: &dyn Graph<Vertex, Weight>, m: &DistanceMatrix) {
+        for u in &m.vertices {
+            for v in &m.vertices {
+                match m.distance(u, v) {
+                    Some(d) => {
+                        let path = m.path(u, v).unwrap();
+                        let mut at = *u;
+                        for (a, b, w) in &path {
+                            assert_eq!(at, *a);
+                            assert_eq!(Some(w), g.get_weight(a, b));
+                            at = *b;
+                        }
+                        assert_eq!(*v, at);
+                        assert_eq!(d, path.iter().map(|e| e.2).sum::<Weight>());
+                    }
+                    None => assert_eq!(None, m.path(u, v)),
+                }
+            }
+        }
+    }
+
+    fn random_directed_graph(rng: &mut Rng) -> DirectedGraph<Vertex, Weight> {
+        let mut g = DirectedGraph::new();
+
+        // Weights shifted by a potential, so there is no negative cycle
+        let potential: Vec<Weight> = (0..8).map(|_| rng.in_range(&(0..=19))).collect();
+        for _ in 0..20 {
+            let (u, v) = (rng.below(8), rng.below(8));
+            if u != v {
+                let w = rng.in_range(&(0..=9));
+                g.add_edge(u + 1, v + 1, w + potential[u] - potential[v]);
+            }
+        }
+
+        g
+    }
+
+    #[test]
+    fn directed_negative_weights() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(1, 3, 8);
+        g.add_edge(1, 5, -4);
+        g.add_edge(2, 4, 1);
+        g.add_edge(2, 5, 7);
+        g.add_edge(3, 2, 4);
+        g.add_edge(4, 1, 2);
+        g.add_edge(4, 3, -5);
+        g.add_edge(5, 4, 6);
+
+        let expected = vec![
+            vec![0, 1, -3, 2, -4],
+            vec![3, 0, -4, 1, -1],
+            vec![7, 4, 0, 5, 3],
+            vec![2, -1, -5, 0, -2],
+            vec![8, 5, 1, 6, 0],
+        ];
+        for res in [floyd_warshall(&g), johnson(&g)] {
+            let m = distances(&res);
+            let current: Vec<Vec<Weight>> = m
+                .distances
+                .iter()
+                .map(|row| row.iter().map(|d| d.unwrap()).collect())
+                .collect();
+            assert_eq!(expected, current);
+            check_paths(&g, m);
+        }
+        assert_eq!(
+            Some(vec![(1, 5, -4), (5, 4, 6), (4, 3, -5), (3, 2, 4)]),
+            distances(&johnson(&g)).path(&1, &2)
+        );
+    }
+
+    #[test]
+    fn undirected_unreachable() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 4);
+        g.add_edge(2, 3, 1);
+        g.add_edge(1, 3, 7);
+        g.add_edge(4, 5, 2);
+
+        for res in [floyd_warshall(&g), johnson(&g)] {
+            let m = distances(&res);
+            assert_eq!(Some(5), m.distance(&3, &1));
+            assert_eq!(None, m.distance(&1, &4));
+            assert_eq!(Some(vec![]), m.path(&4, &4));
+            check_paths(&g, m);
+        }
+    }
+
+    #[test]
+    fn floyd_warshall_matches_johnson() {
+        let mut rng = Rng::new(39);
+        for _ in 0..50 {
+            let g = random_directed_graph(&mut rng);
+            let (fw, j) = (floyd_warshall(&g), johnson(&g));
+
+            assert_eq!(distances(&fw).distances, distances(&j).distances);
+            check_paths(&g, distances(&fw));
+            check_paths(&g, distances(&j));
+        }
+    }
+
+    #[test]
+    fn negative_cycle() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, -3);
+        g.add_edge(3, 1, 1);
+        g.add_edge(3, 4, 2);
+
+        for res in [floyd_warshall(&g), johnson(&g)] {
+            match res {
+                AllPairs::NegativeCycle(cycle) => {
+                    assert_eq!(3, cycle.len());
+                    assert_eq!(-1, cycle.iter().map(|e| e.2).sum::<Weight>());
+                }
+                AllPairs::Distances(_) => panic!("Negative cycle expected"),
+            }
+        }
+    }
+
+    #[test]
+    fn undirected_negative_edge_is_a_cycle() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 2);
+        g.add_edge(2, 3, -1);
+
+        assert!(matches!(floyd_warshall(&g), AllPairs::NegativeCycle(_)));
+        assert!(matches!(johnson(&g), AllPairs::NegativeCycle(_)));
+    }
+
+    #[test]
+    fn csv() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, -1);
+
+        for res in [floyd_warshall(&g), johnson(&g)] {
+            assert_eq!(
+                ",1,2,3\n1,0,3,2\n2,,0,-1\n3,,,0\n",
+                distances(&res).to_csv()
+            );
+        }
+    }
+}
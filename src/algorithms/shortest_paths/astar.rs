@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::shortest_paths::dijkstra::dijkstra,
+    data_structures::priority_queue::{PriorityQueue, PriorityQueueItem},
+    graph::{
+        directed_graph::DirectedGraph,
+        graph::{Graph, Path},
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub trait Heuristic<V, W> {
+    fn estimate(&self, v: &V, target: &V) -> W;
+}
+
+impl<V, W, F: Fn(&V, &V) -> W> Heuristic<V, W> for F {
+    fn estimate(&self, v: &V, target: &V) -> W {
+        self(v, target)
+    }
+}
+
+pub struct ZeroHeuristic;
+
+impl Heuristic<Vertex, Weight> for ZeroHeuristic {
+    fn estimate(&self, _v: &Vertex, _target: &Vertex) -> Weight {
+        0
+    }
+}
+
+/// ALT heuristic: lower bounds from the triangle inequality on the exact
+/// distances from and to a few landmark vertices.
+pub struct LandmarkHeuristic {
+    from_landmarks: Vec<HashMap<Vertex, Weight>>,
+    to_landmarks: Vec<HashMap<Vertex, Weight>>,
+}
+
+impl LandmarkHeuristic {
+    /// Picks `count` landmarks greedily, each one as far as possible from the
+    /// ones already chosen.
+    pub fn new(g: &dyn Graph<Vertex, Weight>, count: usize) -> LandmarkHeuristic {
+        let mut landmarks = vec![];
+        let mut closest: HashMap<Vertex, Weight> = HashMap::new();
+        let mut next = g.get_vertices().iter().min().cloned();
+
+        while let Some(l) = next.filter(|_| landmarks.len() < count) {
+            landmarks.push(l);
+
+            let (distances, _) = dijkstra(g, &l);
+            for (v, d) in distances {
+                let c = closest.entry(v).or_insert(d);
+                *c = (*c).min(d);
+            }
+
+            next = closest
+                .iter()
+                .filter(|(v, _)| !landmarks.contains(v))
+                .max_by_key(|(v, d)| (**d, std::cmp::Reverse(**v)))
+                .map(|(v, _)| *v);
+        }
+
+        LandmarkHeuristic::from_landmarks(g, &landmarks)
+    }
+
+    pub fn from_landmarks(
+        g: &dyn Graph<Vertex, Weight>,
+        landmarks: &[Vertex],
+    ) -> LandmarkHeuristic {
+        let from_landmarks: Vec<HashMap<Vertex, Weight>> =
+            landmarks.iter().map(|l| dijkstra(g, l).0).collect();
+
+        let to_landmarks = if g.is_directed() {
+            let mut reversed = DirectedGraph::new();
+            for (u, v, w) in g.get_edges() {
+                reversed.add_edge(*v, *u, *w);
+            }
+            landmarks.iter().map(|l| dijkstra(&reversed, l).0).collect()
+        } else {
+            from_landmarks.clone()
+        };
+
+        LandmarkHeuristic {
+            from_landmarks,
+            to_landmarks,
+        }
+    }
+}
+
+impl Heuristic<Vertex, Weight> for LandmarkHeuristic {
+    fn estimate(&self, v: &Vertex, target: &Vertex) -> Weight {
+        let forward = self
+            .from_landmarks
+            .iter()
+            .filter_map(|d| Some(d.get(target)? - d.get(v)?));
+        let backward = self
+            .to_landmarks
+            .iter()
+            .filter_map(|d| Some(d.get(v)? - d.get(target)?));
+
+        forward.chain(backward).fold(0, Weight::max)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AStarResult {
+    pub path: Path<Vertex, Weight>,
+    pub cost: Weight,
+    pub expanded: usize,
+}
+
+pub fn run(
+    g: &dyn Graph<Vertex, Weight>,
+    s: &Vertex,
+    t: &Vertex,
+    h: &dyn Heuristic<Vertex, Weight>,
+) -> Option<AStarResult> {
+    astar(g, s, t, h)
+}
+
+/// Shortest path from `s` to `t` guided by an admissible heuristic. Weights
+/// must be non-negative.
+pub fn astar(
+    g: &dyn Graph<Vertex, Weight>,
+    s: &Vertex,
+    t: &Vertex,
+    h: &dyn Heuristic<Vertex, Weight>,
+) -> Option<AStarResult> {
+    if g.get_edges().iter().any(|e| e.2 < 0) {
+        panic!("A* does not support negative weights");
+    }
+    if !g.get_vertices().contains(s) || !g.get_vertices().contains(t) {
+        return None;
+    }
+
+    let mut costs: HashMap<Vertex, Weight> = HashMap::from([(*s, 0)]);
+    let mut parents: HashMap<Vertex, (Vertex, Weight)> = HashMap::new();
+    let mut pq = PriorityQueue::new();
+    let mut expanded = 0;
+
+    pq.insert(PriorityQueueItem(*s, h.estimate(s, t)));
+
+    while let Some(PriorityQueueItem(u, _)) = pq.extract_min() {
+        expanded += 1;
+
+        if u == *t {
+            return Some(AStarResult {
+                path: build_path(&parents, s, t),
+                cost: costs[t],
+                expanded,
+            });
+        }
+
+        for (v, w) in g._get_adj_list(&u).unwrap() {
+            let cost = costs[&u] + w;
+            if costs.get(v).is_some_and(|c| cost >= *c) {
+                continue;
+            }
+
+            costs.insert(*v, cost);
+            parents.insert(*v, (u, *w));
+
+            let priority = cost + h.estimate(v, t);
+            match pq.get_index(v) {
+                Some(i) => pq.decrease_key(i, priority),
+                None => pq.insert(PriorityQueueItem(*v, priority)),
+            }
+        }
+    }
+
+    None
+}
+
+fn build_path(
+    parents: &HashMap<Vertex, (Vertex, Weight)>,
+    s: &Vertex,
+    t: &Vertex,
+) -> Path<Vertex, Weight> {
+    let mut path = vec![];
+    let mut v = *t;
+
+    while v != *s {
+        let (u, w) = parents[&v];
+        path.push((u, v, w));
+        v = u;
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    fn grid(n: usize) -> UndirectedGraph<Vertex, Weight> {
+        let mut g = UndirectedGraph::new();
+
+        for r in 0..n {
+            for c in 0..n {
+                if c + 1 < n {
+                    g.add_edge(r * n + c, r * n + c + 1, 1);
+                }
+                if r + 1 < n {
+                    g.add_edge(r * n + c, (r + 1) * n + c, 1);
+                }
+            }
+        }
+
+        g
+    }
+
+    #[test]
+    fn astar_zero_heuristic() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 7);
+        g.add_edge(1, 3, 9);
+        g.add_edge(1, 6, 14);
+        g.add_edge(2, 3, 10);
+        g.add_edge(2, 4, 15);
+        g.add_edge(3, 4, 11);
+        g.add_edge(3, 6, 2);
+        g.add_edge(4, 5, 6);
+        g.add_edge(5, 6, 9);
+
+        let current = astar(&g, &1, &5, &ZeroHeuristic).unwrap();
+        assert_eq!(vec![(1, 3, 9), (3, 6, 2), (6, 5, 9)], current.path);
+        assert_eq!(20, current.cost);
+    }
+
+    #[test]
+    fn astar_closure_heuristic() {
+        let g = grid(5);
+        let manhattan =
+            |v: &Vertex, t: &Vertex| ((v / 5).abs_diff(t / 5) + (v % 5).abs_diff(t % 5)) as Weight;
+
+        let informed = astar(&g, &0, &24, &manhattan).unwrap();
+        let uninformed = astar(&g, &0, &24, &ZeroHeuristic).unwrap();
+
+        assert_eq!(8, informed.cost);
+        assert_eq!(8, informed.path.len());
+        assert!(informed.expanded < uninformed.expanded);
+    }
+
+    #[test]
+    fn astar_landmark_heuristic() {
+        let g = grid(6);
+        let landmarks = LandmarkHeuristic::new(&g, 2);
+
+        let informed = astar(&g, &7, &28, &landmarks).unwrap();
+        let uninformed = astar(&g, &7, &28, &ZeroHeuristic).unwrap();
+
+        assert_eq!(uninformed.cost, informed.cost);
+        assert!(informed.expanded < uninformed.expanded);
+    }
+
+    #[test]
+    fn astar_landmark_heuristic_directed_graph() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 4);
+        g.add_edge(2, 3, 4);
+        g.add_edge(3, 4, 4);
+        g.add_edge(1, 5, 1);
+        g.add_edge(5, 4, 20);
+        g.add_edge(4, 1, 2);
+
+        let landmarks = LandmarkHeuristic::from_landmarks(&g, &[1, 4]);
+        for u in 1..=5 {
+            for v in 1..=5 {
+                if let Some(exact) = dijkstra(&g, &u).0.get(&v) {
+                    assert!(landmarks.estimate(&u, &v) <= *exact);
+                }
+            }
+        }
+
+        let current = astar(&g, &1, &4, &landmarks).unwrap();
+        assert_eq!(vec![(1, 2, 4), (2, 3, 4), (3, 4, 4)], current.path);
+        assert_eq!(12, current.cost);
+    }
+
+    #[test]
+    #[should_panic]
+    fn astar_negative_weights() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, -1);
+
+        astar(&g, &1, &3, &ZeroHeuristic);
+    }
+
+    #[test]
+    fn astar_unreachable_target() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 4, 1);
+
+        assert_eq!(None, astar(&g, &1, &4, &ZeroHeuristic));
+        assert_eq!(None, astar(&g, &1, &9, &ZeroHeuristic));
+    }
+}
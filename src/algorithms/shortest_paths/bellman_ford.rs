@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use crate::graph::{
+    graph::{Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BellmanFord {
+    Distances(HashMap<Vertex, Weight>, HashMap<Vertex, Vertex>),
+    NegativeCycle(Path<Vertex, Weight>),
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, s: &Vertex) -> BellmanFord {
+    bellman_ford(g, s)
+}
+
+/// Distances and parents of every vertex reachable from `s`, or a negative
+/// cycle reachable from it. An undirected edge can be walked both ways, so a
+/// negative one is a cycle by itself.
+pub fn bellman_ford(g: &dyn Graph<Vertex, Weight>, s: &Vertex) -> BellmanFord {
+    if !g.get_vertices().contains(s) {
+        return BellmanFord::Distances(HashMap::new(), HashMap::new());
+    }
+
+    relax(g, HashMap::from([(*s, 0)]))
+}
+
+/// Distances from a virtual source joined to every vertex by a 0 edge, the
+/// potentials Johnson's algorithm reweights with. Finds any negative cycle.
+pub fn potentials(g: &dyn Graph<Vertex, Weight>) -> BellmanFord {
+    relax(g, g.get_vertices().iter().map(|v| (*v, 0)).collect())
+}
+
+fn relax(g: &dyn Graph<Vertex, Weight>, mut distances: HashMap<Vertex, Weight>) -> BellmanFord {
+    let mut parents: HashMap<Vertex, Vertex> = HashMap::new();
+    let mut arcs: Vec<(Vertex, Vertex, Weight)> = g
+        .get_vertices()
+        .iter()
+        .flat_map(|u| {
+            g._get_adj_list(u)
+                .unwrap()
+                .iter()
+                .map(move |(v, w)| (*u, *v, *w))
+        })
+        .collect();
+    arcs.sort();
+
+    // A relaxation in the n-th round means a negative cycle
+    let mut last_relaxed = None;
+    for _ in 0..g.get_vertices().len() {
+        last_relaxed = None;
+
+        for (u, v, w) in &arcs {
+            if let Some(du) = distances.get(u) {
+                let d = du.saturating_add(*w);
+                if distances.get(v).is_none_or(|dv| d < *dv) {
+                    distances.insert(*v, d);
+                    parents.insert(*v, *u);
+                    last_relaxed = Some(*v);
+                }
+            }
+        }
+
+        if last_relaxed.is_none() {
+            return BellmanFord::Distances(distances, parents);
+        }
+    }
+
+    // Walking n parents back from a vertex relaxed last lands on the cycle
+    let mut v = last_relaxed.unwrap();
+    for _ in 0..g.get_vertices().len() {
+        v = parents[&v];
+    }
+    let mut cycle = vec![];
+    let mut u = v;
+    loop {
+        let p = parents[&u];
+        cycle.push((p, u, *g.get_weight(&p, &u).unwrap()));
+        u = p;
+        if u == v {
+            break;
+        }
+    }
+    cycle.reverse();
+
+    BellmanFord::NegativeCycle(cycle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::shortest_paths::dijkstra::dijkstra;
+    use crate::graph::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+    fn is_negative_cycle(g: &dyn Graph<Vertex, Weight>, cycle: &Path<Vertex, Weight>) -> bool {
+        let closed = (0..cycle.len()).all(|i| cycle[i].1 == cycle[(i + 1) % cycle.len()].0);
+        let edges = cycle.iter().all(|(u, v, w)| g.get_weight(u, v) == Some(w));
+
+        closed && edges && cycle.iter().map(|e| e.2).sum::<Weight>() < 0
+    }
+
+    #[test]
+    fn negative_weights() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 4);
+        g.add_edge(1, 3, 5);
+        g.add_edge(2, 4, -3);
+        g.add_edge(3, 2, -2);
+        g.add_edge(4, 5, 2);
+        g.add_edge(6, 1, 1);
+
+        let expected_distances = HashMap::from([(1, 0), (2, 3), (3, 5), (4, 0), (5, 2)]);
+        let expected_parents = HashMap::from([(2, 3), (3, 1), (4, 2), (5, 4)]);
+        assert_eq!(
+            BellmanFord::Distances(expected_distances, expected_parents),
+            bellman_ford(&g, &1)
+        );
+    }
+
+    #[test]
+    fn matches_dijkstra_on_non_negative_weights() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 7);
+        g.add_edge(1, 3, 9);
+        g.add_edge(1, 6, 14);
+        g.add_edge(2, 3, 10);
+        g.add_edge(2, 4, 15);
+        g.add_edge(3, 4, 11);
+        g.add_edge(3, 6, 2);
+        g.add_edge(4, 5, 6);
+        g.add_edge(5, 6, 9);
+
+        match bellman_ford(&g, &1) {
+            BellmanFord::Distances(distances, _) => {
+                assert_eq!(dijkstra(&g, &1).0, distances)
+            }
+            BellmanFord::NegativeCycle(_) => panic!("No negative cycle expected"),
+        }
+    }
+
+    #[test]
+    fn negative_cycle() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, -4);
+        g.add_edge(4, 2, 1);
+        g.add_edge(4, 5, 1);
+
+        match bellman_ford(&g, &1) {
+            BellmanFord::NegativeCycle(cycle) => {
+                assert_eq!(3, cycle.len());
+                assert!(is_negative_cycle(&g, &cycle));
+            }
+            BellmanFord::Distances(_, _) => panic!("Negative cycle expected"),
+        }
+    }
+
+    #[test]
+    fn unreachable_negative_cycle() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 4, -1);
+        g.add_edge(4, 3, -1);
+
+        assert!(matches!(bellman_ford(&g, &1), BellmanFord::Distances(_, _)));
+        match potentials(&g) {
+            BellmanFord::NegativeCycle(cycle) => assert!(is_negative_cycle(&g, &cycle)),
+            BellmanFord::Distances(_, _) => panic!("Negative cycle expected"),
+        }
+    }
+
+    #[test]
+    fn undirected_negative_edge() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, -1);
+
+        match bellman_ford(&g, &1) {
+            BellmanFord::NegativeCycle(cycle) => {
+                assert_eq!(2, cycle.len());
+                assert!(is_negative_cycle(&g, &cycle));
+            }
+            BellmanFord::Distances(_, _) => panic!("Negative cycle expected"),
+        }
+    }
+
+    #[test]
+    fn potentials_without_negative_edges() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, -1);
+
+        let expected = HashMap::from([(1, 0), (2, 0), (3, -1)]);
+        match potentials(&g) {
+            BellmanFord::Distances(distances, _) => assert_eq!(expected, distances),
+            BellmanFord::NegativeCycle(_) => panic!("No negative cycle expected"),
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::{
+    data_structures::priority_queue::{PriorityQueue, PriorityQueueItem},
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub fn run(
+    g: &dyn Graph<Vertex, Weight>,
+    s: &Vertex,
+) -> (HashMap<Vertex, Weight>, HashMap<Vertex, Vertex>) {
+    dijkstra(g, s)
+}
+
+/// Distances and parents of every vertex reachable from `s`. Weights must be
+/// non-negative.
+pub fn dijkstra(
+    g: &dyn Graph<Vertex, Weight>,
+    s: &Vertex,
+) -> (HashMap<Vertex, Weight>, HashMap<Vertex, Vertex>) {
+    let mut distances = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut pq = PriorityQueue::new();
+
+    if g.get_edges().iter().any(|e| e.2 < 0) {
+        panic!("Dijkstra does not support negative weights");
+    }
+    if !g.get_vertices().contains(s) {
+        return (distances, parents);
+    }
+
+    for v in g.get_vertices() {
+        pq.insert(PriorityQueueItem(*v, if v != s { Weight::MAX } else { 0 }));
+    }
+
+    while let Some(PriorityQueueItem(u, d)) = pq.extract_min() {
+        if d == Weight::MAX {
+            break;
+        }
+        distances.insert(u, d);
+
+        for (v, w) in g._get_adj_list(&u).unwrap() {
+            if let Some(i) = pq.get_index(v) {
+                let t = pq.get_element(i).unwrap();
+                if d.saturating_add(*w) < t.1 {
+                    parents.insert(*v, u);
+                    pq.decrease_key(i, d + *w);
+                }
+            }
+        }
+    }
+
+    (distances, parents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{directed_graph::DirectedGraph, undirected_graph::UndirectedGraph};
+
+    #[test]
+    fn dijkstra_simple_graph() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 7);
+        g.add_edge(1, 3, 9);
+        g.add_edge(1, 6, 14);
+        g.add_edge(2, 3, 10);
+        g.add_edge(2, 4, 15);
+        g.add_edge(3, 4, 11);
+        g.add_edge(3, 6, 2);
+        g.add_edge(4, 5, 6);
+        g.add_edge(5, 6, 9);
+
+        let (distances, parents) = dijkstra(&g, &1);
+
+        let expected_distances = HashMap::from([(1, 0), (2, 7), (3, 9), (4, 20), (5, 20), (6, 11)]);
+        assert_eq!(expected_distances, distances);
+
+        let expected_parents = HashMap::from([(2, 1), (3, 1), (4, 3), (5, 6), (6, 3)]);
+        assert_eq!(expected_parents, parents);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dijkstra_negative_weights() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, -1);
+
+        dijkstra(&g, &1);
+    }
+
+    #[test]
+    fn dijkstra_unreachable_vertices() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(3, 1, 1);
+        g.add_edge(4, 5, 1);
+
+        let (distances, _) = dijkstra(&g, &1);
+
+        let expected = HashMap::from([(1, 0), (2, 3)]);
+        assert_eq!(expected, distances);
+    }
+}
@@ -0,0 +1,13 @@
+pub mod dfs;
+pub mod kahn;
+
+use crate::graph::{
+    graph::Path,
+    undirected_graph::{Vertex, Weight},
+};
+
+/// A directed cycle that prevents the topological sort, its edges in order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CycleError {
+    pub cycle: Path<Vertex, Weight>,
+}
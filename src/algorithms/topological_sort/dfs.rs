@@ -0,0 +1,161 @@
+use std::collections::HashMap;
+
+use crate::algorithms::topological_sort::CycleError;
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    Open,
+    Closed,
+}
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> Result<Vec<Vertex>, CycleError> {
+    dfs(g)
+}
+
+/// Reverse DFS finishing order. An edge back to a vertex still on the DFS
+/// stack closes the reported cycle. Iterative; vertices and neighbours are
+/// visited in increasing order, so the result is deterministic.
+pub fn dfs(g: &dyn Graph<Vertex, Weight>) -> Result<Vec<Vertex>, CycleError> {
+    if !g.is_directed() {
+        panic!("Topological sort is defined on directed graphs");
+    }
+
+    let mut state: HashMap<Vertex, State> = HashMap::new();
+    let mut order = vec![];
+    let neighbours = |u: &Vertex| -> Vec<Vertex> {
+        let mut adj: Vec<Vertex> = g._get_adj_list(u).unwrap().keys().copied().collect();
+        adj.sort();
+        adj
+    };
+
+    let mut roots: Vec<&Vertex> = g.get_vertices().iter().collect();
+    roots.sort();
+
+    for root in roots {
+        if state.contains_key(root) {
+            continue;
+        }
+
+        state.insert(*root, State::Open);
+        let mut stack = vec![(*root, neighbours(root), 0)];
+        while let Some((u, adj, i)) = stack.last_mut() {
+            let u = *u;
+            match adj.get(*i).copied() {
+                Some(v) => {
+                    *i += 1;
+                    match state.get(&v) {
+                        None => {
+                            state.insert(v, State::Open);
+                            stack.push((v, neighbours(&v), 0));
+                        }
+                        Some(State::Open) => {
+                            let from = stack.iter().position(|(x, _, _)| *x == v).unwrap();
+                            let mut path: Vec<Vertex> =
+                                stack[from..].iter().map(|(x, _, _)| *x).collect();
+                            path.push(v);
+
+                            let cycle = path
+                                .windows(2)
+                                .map(|e| (e[0], e[1], *g.get_weight(&e[0], &e[1]).unwrap()))
+                                .collect();
+                            return Err(CycleError { cycle });
+                        }
+                        Some(State::Closed) => {}
+                    }
+                }
+                None => {
+                    state.insert(u, State::Closed);
+                    order.push(u);
+                    stack.pop();
+                }
+            }
+        }
+    }
+    order.reverse();
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::topological_sort::kahn::kahn;
+    use crate::graph::{
+        directed_graph::DirectedGraph,
+        generators::{rng::Rng, testing::random_edges},
+    };
+
+    #[test]
+    fn dependencies() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(5, 11, 1);
+        g.add_edge(7, 11, 1);
+        g.add_edge(7, 8, 1);
+        g.add_edge(3, 8, 1);
+        g.add_edge(3, 10, 1);
+        g.add_edge(11, 2, 1);
+        g.add_edge(11, 9, 1);
+        g.add_edge(11, 10, 1);
+        g.add_edge(8, 9, 1);
+
+        assert_eq!(Ok(vec![7, 5, 11, 3, 10, 8, 9, 2]), dfs(&g));
+    }
+
+    #[test]
+    fn cycle() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 2);
+        g.add_edge(3, 4, 3);
+        g.add_edge(4, 2, 4);
+
+        assert_eq!(
+            Err(CycleError {
+                cycle: vec![(2, 3, 2), (3, 4, 3), (4, 2, 4)]
+            }),
+            dfs(&g)
+        );
+    }
+
+    #[test]
+    fn agrees_with_kahn() {
+        let mut rng = Rng::new(29);
+
+        for _ in 0..200 {
+            let mut g = DirectedGraph::new();
+            random_edges(&mut g, 12, 15, &(1..=1), &mut rng);
+
+            match (dfs(&g), kahn(&g, true)) {
+                (Ok(order), Ok(_)) => {
+                    let position: HashMap<Vertex, usize> =
+                        order.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+                    assert!(g
+                        .get_edges()
+                        .iter()
+                        .all(|(u, v, _)| position[u] < position[v]));
+                }
+                (Err(CycleError { cycle }), Err(_)) => {
+                    assert!((0..cycle.len()).all(|i| cycle[i].1 == cycle[(i + 1) % cycle.len()].0))
+                }
+                _ => panic!("Kahn and DFS disagree on acyclicity"),
+            }
+        }
+    }
+
+    #[test]
+    fn long_chain() {
+        let mut g = DirectedGraph::new();
+
+        for v in 0..100_000 {
+            g.add_edge(v, v + 1, 1);
+        }
+
+        assert_eq!(Ok((0..=100_000).collect()), dfs(&g));
+    }
+}
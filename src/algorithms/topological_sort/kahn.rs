@@ -0,0 +1,221 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+
+use crate::algorithms::topological_sort::CycleError;
+use crate::graph::{
+    graph::{Graph, Path},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, lexicographic: bool) -> Result<Vec<Vertex>, CycleError> {
+    kahn(g, lexicographic)
+}
+
+/// Repeatedly removes a vertex without incoming edges. With `lexicographic`
+/// the smallest such vertex goes first, which gives the lexicographically
+/// smallest order; otherwise ties are taken first in, first out.
+pub fn kahn(g: &dyn Graph<Vertex, Weight>, lexicographic: bool) -> Result<Vec<Vertex>, CycleError> {
+    if !g.is_directed() {
+        panic!("Topological sort is defined on directed graphs");
+    }
+
+    let mut in_degree: HashMap<Vertex, usize> = g.get_vertices().iter().map(|v| (*v, 0)).collect();
+    for (_, v, _) in g.get_edges() {
+        *in_degree.get_mut(v).unwrap() += 1;
+    }
+
+    let mut sources: Vec<Vertex> = in_degree
+        .iter()
+        .filter(|(_, d)| **d == 0)
+        .map(|(v, _)| *v)
+        .collect();
+    sources.sort();
+    let mut heap: BinaryHeap<Reverse<Vertex>> = BinaryHeap::new();
+    let mut queue: VecDeque<Vertex> = VecDeque::new();
+    for v in sources {
+        if lexicographic {
+            heap.push(Reverse(v));
+        } else {
+            queue.push_back(v);
+        }
+    }
+
+    let mut order = vec![];
+    loop {
+        let u = if lexicographic {
+            heap.pop().map(|Reverse(u)| u)
+        } else {
+            queue.pop_front()
+        };
+        let Some(u) = u else {
+            break;
+        };
+        order.push(u);
+
+        let mut adj: Vec<&Vertex> = g._get_adj_list(&u).unwrap().keys().collect();
+        adj.sort();
+        for v in adj {
+            let d = in_degree.get_mut(v).unwrap();
+            *d -= 1;
+            if *d == 0 {
+                if lexicographic {
+                    heap.push(Reverse(*v));
+                } else {
+                    queue.push_back(*v);
+                }
+            }
+        }
+    }
+
+    if order.len() < g.get_vertices().len() {
+        let sorted: HashSet<Vertex> = order.into_iter().collect();
+        return Err(CycleError {
+            cycle: remaining_cycle(g, &sorted),
+        });
+    }
+
+    Ok(order)
+}
+
+/// Every vertex left unsorted has an unsorted predecessor, so walking
+/// predecessors backwards from one of them eventually repeats a vertex.
+fn remaining_cycle(
+    g: &dyn Graph<Vertex, Weight>,
+    sorted: &HashSet<Vertex>,
+) -> Path<Vertex, Weight> {
+    let mut predecessor: HashMap<Vertex, Vertex> = HashMap::new();
+    let mut edges: Vec<_> = g.get_edges().iter().collect();
+    edges.sort();
+    for (u, v, _) in edges {
+        if !sorted.contains(u) && !sorted.contains(v) {
+            predecessor.entry(*v).or_insert(*u);
+        }
+    }
+
+    let mut v = *predecessor.keys().min().unwrap();
+    let mut seen: HashSet<Vertex> = HashSet::new();
+    while seen.insert(v) {
+        v = predecessor[&v];
+    }
+
+    let mut cycle = vec![];
+    let start = v;
+    loop {
+        let u = predecessor[&v];
+        cycle.push((u, v, *g.get_weight(&u, &v).unwrap()));
+        v = u;
+        if v == start {
+            break;
+        }
+    }
+    cycle.reverse();
+
+    cycle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{
+        directed_graph::DirectedGraph,
+        generators::{rng::Rng, testing::random_edges},
+    };
+
+    fn is_topological_order(g: &dyn Graph<Vertex, Weight>, order: &[Vertex]) -> bool {
+        let position: HashMap<Vertex, usize> =
+            order.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+        order.len() == g.get_vertices().len()
+            && g.get_edges()
+                .iter()
+                .all(|(u, v, _)| position[u] < position[v])
+    }
+
+    fn is_cycle(g: &dyn Graph<Vertex, Weight>, cycle: &Path<Vertex, Weight>) -> bool {
+        !cycle.is_empty()
+            && (0..cycle.len()).all(|i| cycle[i].1 == cycle[(i + 1) % cycle.len()].0)
+            && cycle.iter().all(|(u, v, w)| g.get_weight(u, v) == Some(w))
+    }
+
+    fn dependencies() -> DirectedGraph<Vertex, Weight> {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(5, 11, 1);
+        g.add_edge(7, 11, 1);
+        g.add_edge(7, 8, 1);
+        g.add_edge(3, 8, 1);
+        g.add_edge(3, 10, 1);
+        g.add_edge(11, 2, 1);
+        g.add_edge(11, 9, 1);
+        g.add_edge(11, 10, 1);
+        g.add_edge(8, 9, 1);
+
+        g
+    }
+
+    #[test]
+    fn lexicographic_order() {
+        let g = dependencies();
+
+        assert_eq!(Ok(vec![3, 5, 7, 8, 11, 2, 9, 10]), kahn(&g, true));
+    }
+
+    #[test]
+    fn fifo_order() {
+        let g = dependencies();
+        let order = kahn(&g, false).unwrap();
+
+        assert!(is_topological_order(&g, &order));
+        assert_eq!(vec![3, 5, 7], order[..3]);
+    }
+
+    #[test]
+    fn cycle() {
+        let mut g = dependencies();
+        g.add_edge(9, 7, 4);
+
+        let cycle = kahn(&g, true).unwrap_err().cycle;
+        assert!(is_cycle(&g, &cycle));
+        assert_eq!(3, cycle.len());
+    }
+
+    #[test]
+    fn self_loop() {
+        let mut g = DirectedGraph::new();
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 2, 3);
+
+        assert_eq!(
+            Err(CycleError {
+                cycle: vec![(2, 2, 3)]
+            }),
+            kahn(&g, false)
+        );
+    }
+
+    #[test]
+    fn random_graphs() {
+        let mut rng = Rng::new(23);
+
+        for _ in 0..200 {
+            let mut g = DirectedGraph::new();
+            random_edges(&mut g, 12, 15, &(1..=1), &mut rng);
+
+            for lexicographic in [true, false] {
+                match kahn(&g, lexicographic) {
+                    Ok(order) => assert!(is_topological_order(&g, &order)),
+                    Err(CycleError { cycle }) => assert!(is_cycle(&g, &cycle)),
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn undirected_graph() {
+        let mut g = crate::graph::undirected_graph::UndirectedGraph::new();
+        g.add_edge(1, 2, 1);
+
+        kahn(&g, true).ok();
+    }
+}
@@ -0,0 +1,6 @@
+pub mod christofides;
+pub mod double_tree;
+pub mod held_karp;
+pub mod local_search;
+pub mod metric_closure;
+pub mod tour;
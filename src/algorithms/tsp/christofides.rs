@@ -0,0 +1,90 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    algorithms::{
+        euler, matching::weighted_matching::min_weight_perfect_matching,
+        minimum_spanning_tree::prim::prim, tsp::tour::Tour,
+    },
+    graph::{
+        graph::Graph,
+        undirected_graph::{UndirectedGraph, Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, start: &Vertex) -> Tour {
+    christofides(g, start)
+}
+
+/// Adds a minimum-weight perfect matching of the odd-degree vertices to
+/// Prim's tree, walks an Eulerian circuit of the result from `s` and skips
+/// repeated vertices. On a complete graph whose weights obey the triangle
+/// inequality the tour costs at most 3/2 of the optimum.
+pub fn christofides(g: &dyn Graph<Vertex, Weight>, s: &Vertex) -> Tour {
+    let mut edges = prim(g, s);
+
+    let mut degrees: HashMap<Vertex, usize> = HashMap::new();
+    for (u, v, _) in &edges {
+        *degrees.entry(*u).or_default() += 1;
+        *degrees.entry(*v).or_default() += 1;
+    }
+    let odd: HashSet<Vertex> = degrees
+        .into_iter()
+        .filter(|(_, d)| d % 2 == 1)
+        .map(|(v, _)| v)
+        .collect();
+
+    let mut odd_subgraph = UndirectedGraph::new();
+    for (u, v, w) in g.get_edges() {
+        if u != v && odd.contains(u) && odd.contains(v) {
+            odd_subgraph.add_edge(*u, *v, *w);
+        }
+    }
+    edges.extend(
+        min_weight_perfect_matching(&odd_subgraph)
+            .unwrap_or_else(|| panic!("Graph is not complete")),
+    );
+
+    let circuit = euler::hierholzer::circuit(&edges, false, Some(s))
+        .unwrap_or_else(|_| panic!("Graph is not connected"));
+    let mut visited = HashSet::new();
+    let vertices = std::iter::once(*s)
+        .chain(circuit.into_iter().map(|e| e.1))
+        .filter(|v| visited.insert(*v))
+        .collect();
+
+    Tour::from(g, vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{
+        rng::Rng,
+        testing::{manhattan, optimum, random_points},
+    };
+
+    #[test]
+    fn christofides_star_of_points() {
+        let g = manhattan(&[(0, 0), (0, 2), (2, 0), (0, -2), (-2, 0)]);
+
+        let current = christofides(&g, &0);
+        assert_eq!(0, current.vertices[0]);
+        assert_eq!(5, current.vertices.len());
+        assert!(2 * current.cost <= 3 * 16);
+    }
+
+    #[test]
+    fn christofides_within_factor_three_halves() {
+        let mut rng = Rng::new(13);
+        for _ in 0..10 {
+            let g = manhattan(&random_points(8, &mut rng));
+
+            let current = christofides(&g, &0);
+            let mut sorted = current.vertices.clone();
+            sorted.sort();
+            assert_eq!((0..8).collect::<Vec<Vertex>>(), sorted);
+            assert_eq!(0, current.vertices[0]);
+            assert!(2 * current.cost <= 3 * optimum(&g, 8).unwrap());
+        }
+    }
+}
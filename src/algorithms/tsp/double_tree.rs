@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorithms::{minimum_spanning_tree::prim::prim, tsp::tour::Tour},
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, start: &Vertex) -> Tour {
+    double_tree(g, start)
+}
+
+/// Visits the vertices in preorder of Prim's tree rooted at `s`, which is the
+/// tree walked twice with repeated vertices skipped. On a complete graph whose
+/// weights obey the triangle inequality the tour costs at most twice the
+/// optimum.
+pub fn double_tree(g: &dyn Graph<Vertex, Weight>, s: &Vertex) -> Tour {
+    let mut children: HashMap<Vertex, Vec<Vertex>> = HashMap::new();
+    for (p, v, _) in prim(g, s) {
+        children.entry(p).or_default().push(v);
+    }
+
+    let mut vertices = vec![];
+    let mut stack = vec![*s];
+    while let Some(u) = stack.pop() {
+        vertices.push(u);
+        if let Some(c) = children.get(&u) {
+            stack.extend(c.iter().rev());
+        }
+    }
+
+    Tour::from(g, vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{
+        rng::Rng,
+        testing::{manhattan, optimum, random_points},
+    };
+
+    #[test]
+    fn double_tree_points_on_a_line() {
+        let g = manhattan(&[(0, 0), (1, 0), (3, 0), (6, 1)]);
+
+        let expected = Tour {
+            vertices: vec![0, 1, 2, 3],
+            cost: 14,
+        };
+        let current = double_tree(&g, &0);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn double_tree_within_factor_two() {
+        let mut rng = Rng::new(5);
+        for _ in 0..10 {
+            let g = manhattan(&random_points(7, &mut rng));
+
+            let current = double_tree(&g, &0);
+            let mut sorted = current.vertices.clone();
+            sorted.sort();
+            assert_eq!((0..7).collect::<Vec<Vertex>>(), sorted);
+            assert_eq!(0, current.vertices[0]);
+            assert!(current.cost <= 2 * optimum(&g, 7).unwrap());
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    algorithms::tsp::tour::Tour,
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, start: &Vertex) -> Option<Tour> {
+    held_karp(g, start)
+}
+
+/// Optimal tour from `s` by dynamic programming over the subsets of visited
+/// vertices, in O(2ⁿn²) time and O(2ⁿn) memory: use it on graphs with up to
+/// about 20 vertices. The graph does not have to be complete nor symmetric;
+/// None means it has no Hamiltonian cycle.
+pub fn held_karp(g: &dyn Graph<Vertex, Weight>, s: &Vertex) -> Option<Tour> {
+    if !g.get_vertices().contains(s) {
+        return None;
+    }
+
+    let mut vertices: Vec<Vertex> = g
+        .get_vertices()
+        .iter()
+        .filter(|v| *v != s)
+        .cloned()
+        .collect();
+    vertices.sort();
+    let n = vertices.len();
+    if n >= 20 {
+        panic!("Held-Karp supports at most 20 vertices");
+    }
+    if n == 0 {
+        return Some(Tour::from(g, vec![*s]));
+    }
+
+    // Index n stands for `s`
+    let weights: Vec<Vec<Option<i64>>> = vertices
+        .iter()
+        .chain([s])
+        .map(|u| {
+            vertices
+                .iter()
+                .chain([s])
+                .map(|v| g.get_weight(u, v).map(|w| *w as i64))
+                .collect()
+        })
+        .collect();
+
+    // costs[mask * n + j]: cheapest path from `s` through the vertices of
+    // `mask`, ending in j
+    let mut costs = vec![i64::MAX; (1 << n) * n];
+    for j in 0..n {
+        if let Some(w) = weights[n][j] {
+            costs[(1 << j) * n + j] = w;
+        }
+    }
+    for mask in 1..1usize << n {
+        for j in (0..n).filter(|j| mask & (1 << j) != 0) {
+            let cost = costs[mask * n + j];
+            if cost == i64::MAX {
+                continue;
+            }
+
+            for k in (0..n).filter(|k| mask & (1 << k) == 0) {
+                if let Some(w) = weights[j][k] {
+                    let next = &mut costs[(mask | (1 << k)) * n + k];
+                    *next = (*next).min(cost + w);
+                }
+            }
+        }
+    }
+
+    let full = (1 << n) - 1;
+    let (mut last, _) = (0..n)
+        .filter_map(|j| {
+            let cost = costs[full * n + j];
+            weights[j][n]
+                .filter(|_| cost != i64::MAX)
+                .map(|w| (j, cost + w))
+        })
+        .min_by_key(|(j, cost)| (*cost, *j))?;
+
+    let mut tour = vec![];
+    let mut mask = full;
+    loop {
+        tour.push(vertices[last]);
+        let cost = costs[mask * n + last];
+        mask ^= 1 << last;
+        if mask == 0 {
+            break;
+        }
+
+        last = (0..n)
+            .find(|i| {
+                mask & (1 << i) != 0
+                    && costs[mask * n + i] != i64::MAX
+                    && weights[*i][last].is_some_and(|w| costs[mask * n + i] + w == cost)
+            })
+            .unwrap();
+    }
+    tour.push(*s);
+    tour.reverse();
+
+    Some(Tour::from(g, tour))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{
+        directed_graph::DirectedGraph,
+        generators::{rng::Rng, testing::optimum},
+        undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn held_karp_square() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 1, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(2, 4, 5);
+
+        let expected = Tour {
+            vertices: vec![1, 4, 3, 2],
+            cost: 4,
+        };
+        let current = held_karp(&g, &1).unwrap();
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn held_karp_matches_brute_force() {
+        let mut rng = Rng::new(17);
+        for round in 0..20 {
+            let mut g: Box<dyn Graph<Vertex, Weight>> = if round % 2 == 0 {
+                Box::new(UndirectedGraph::new())
+            } else {
+                Box::new(DirectedGraph::new())
+            };
+            for u in 0..7 {
+                for v in 0..7 {
+                    if u != v && rng.below(4) != 0 {
+                        g.add_edge(u, v, rng.in_range(&(-10..=89)));
+                    }
+                }
+            }
+
+            let expected = optimum(g.as_ref(), 7);
+            let current = held_karp(g.as_ref(), &0);
+            assert_eq!(expected, current.as_ref().map(|t| t.cost));
+            if let Some(tour) = current {
+                assert_eq!(0, tour.vertices[0]);
+                assert_eq!(7, tour.vertices.len());
+            }
+        }
+    }
+
+    #[test]
+    fn held_karp_no_hamiltonian_cycle() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(1, 3, 1);
+        g.add_edge(1, 4, 1);
+
+        assert_eq!(None, held_karp(&g, &1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Held-Karp supports at most 20 vertices")]
+    fn held_karp_too_many_vertices() {
+        let mut g = UndirectedGraph::new();
+        for v in 1..21 {
+            g.add_edge(0, v, 1);
+        }
+
+        held_karp(&g, &0);
+    }
+
+    #[test]
+    fn held_karp_single_vertex() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 1, 3);
+
+        let expected = Tour {
+            vertices: vec![1],
+            cost: 0,
+        };
+        assert_eq!(Some(expected), held_karp(&g, &1));
+    }
+}
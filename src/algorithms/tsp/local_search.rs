@@ -0,0 +1,160 @@
+use std::time::{Duration, Instant};
+
+use crate::{
+    algorithms::tsp::tour::Tour,
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, tour: &Tour, time_limit: Option<Duration>) -> Tour {
+    local_search(g, tour, time_limit)
+}
+
+/// Improves `tour` with 2-opt and Or-opt moves until neither finds an
+/// improvement or `time_limit` runs out. Moves assume a complete graph with
+/// symmetric weights; the first vertex of the tour stays in place.
+pub fn local_search(
+    g: &dyn Graph<Vertex, Weight>,
+    tour: &Tour,
+    time_limit: Option<Duration>,
+) -> Tour {
+    let start = Instant::now();
+    let mut vertices = tour.vertices.clone();
+
+    while time_limit.is_none_or(|limit| start.elapsed() < limit) {
+        if !two_opt(g, &mut vertices) && !or_opt(g, &mut vertices) {
+            break;
+        }
+    }
+
+    Tour::from(g, vertices)
+}
+
+/// Applies the first improving 2-opt move: edges (a, b) and (c, d) are
+/// replaced by (a, c) and (b, d), reversing the path from b to c.
+pub fn two_opt(g: &dyn Graph<Vertex, Weight>, vertices: &mut [Vertex]) -> bool {
+    let n = vertices.len();
+
+    for i in 0..n.saturating_sub(2) {
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+
+            let (a, b) = (vertices[i], vertices[i + 1]);
+            let (c, d) = (vertices[j], vertices[(j + 1) % n]);
+            if weight(g, a, c) + weight(g, b, d) < weight(g, a, b) + weight(g, c, d) {
+                vertices[i + 1..=j].reverse();
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Applies the first improving Or-opt move: a segment of up to three
+/// consecutive vertices is moved, possibly reversed, between two other
+/// neighbours of the tour.
+pub fn or_opt(g: &dyn Graph<Vertex, Weight>, vertices: &mut Vec<Vertex>) -> bool {
+    let n = vertices.len();
+
+    for len in 1..=3.min(n.saturating_sub(2)) {
+        for i in 1..=n - len {
+            let (first, last) = (vertices[i], vertices[i + len - 1]);
+            let (prev, next) = (vertices[i - 1], vertices[(i + len) % n]);
+            let gain = weight(g, prev, first) + weight(g, last, next) - weight(g, prev, next);
+
+            for k in (0..n).filter(|k| *k + 1 < i || *k >= i + len) {
+                let (p, q) = (vertices[k], vertices[(k + 1) % n]);
+                let forward = weight(g, p, first) + weight(g, last, q) - weight(g, p, q);
+                let backward = weight(g, p, last) + weight(g, first, q) - weight(g, p, q);
+                if forward.min(backward) < gain {
+                    let mut segment: Vec<Vertex> = vertices.drain(i..i + len).collect();
+                    if backward < forward {
+                        segment.reverse();
+                    }
+                    let at = if k < i { k + 1 } else { k + 1 - len };
+                    vertices.splice(at..at, segment);
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+fn weight(g: &dyn Graph<Vertex, Weight>, u: Vertex, v: Vertex) -> i64 {
+    *g.get_weight(&u, &v)
+        .unwrap_or_else(|| panic!("Missing edge ({}, {})", u, v)) as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::generators::{
+        rng::Rng,
+        testing::{manhattan, random_points},
+    };
+
+    #[test]
+    fn two_opt_uncrosses_tour() {
+        let g = manhattan(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+        let mut vertices = vec![0, 2, 1, 3];
+
+        assert!(two_opt(&g, &mut vertices));
+        assert_eq!(vec![0, 1, 2, 3], vertices);
+        assert!(!two_opt(&g, &mut vertices));
+    }
+
+    #[test]
+    fn or_opt_moves_vertex() {
+        let g = manhattan(&[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0)]);
+        let mut vertices = vec![0, 2, 3, 1, 4, 5];
+
+        assert!(or_opt(&g, &mut vertices));
+        assert_eq!(vec![0, 2, 1, 3, 4, 5], vertices);
+    }
+
+    #[test]
+    fn local_search_line() {
+        let g = manhattan(&[(0, 0), (1, 0), (2, 0), (3, 0), (4, 0), (5, 0)]);
+        let tour = Tour::from(&g, vec![0, 4, 2, 5, 1, 3]);
+
+        let current = local_search(&g, &tour, None);
+        assert_eq!(0, current.vertices[0]);
+        assert_eq!(10, current.cost);
+    }
+
+    #[test]
+    fn local_search_never_worsens() {
+        let mut rng = Rng::new(21);
+        for _ in 0..10 {
+            let g = manhattan(&random_points(12, &mut rng));
+            let tour = Tour::from(&g, (0..12).collect());
+
+            let current = local_search(&g, &tour, None);
+            let mut sorted = current.vertices.clone();
+            sorted.sort();
+            assert_eq!(tour.vertices, sorted);
+            assert_eq!(0, current.vertices[0]);
+            assert!(current.cost <= tour.cost);
+
+            let mut vertices = current.vertices.clone();
+            assert!(!two_opt(&g, &mut vertices));
+            assert!(!or_opt(&g, &mut vertices));
+        }
+    }
+
+    #[test]
+    fn local_search_without_time() {
+        let g = manhattan(&[(0, 0), (0, 2), (2, 2), (2, 0)]);
+        let tour = Tour::from(&g, vec![0, 2, 1, 3]);
+
+        let current = local_search(&g, &tour, Some(Duration::ZERO));
+        assert_eq!(tour, current);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    algorithms::shortest_paths::dijkstra::dijkstra,
+    graph::{
+        graph::Graph,
+        undirected_graph::{UndirectedGraph, Vertex, Weight},
+    },
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> UndirectedGraph<Vertex, Weight> {
+    metric_closure(g)
+}
+
+/// Complete graph on the vertices of `g` where every edge weighs the shortest
+/// path distance between its endpoints. A tour of the closure costs the same
+/// as the closed walk it stands for in `g`. Weights must be non-negative and
+/// the graph connected.
+pub fn metric_closure(g: &dyn Graph<Vertex, Weight>) -> UndirectedGraph<Vertex, Weight> {
+    let mut closure = UndirectedGraph::new();
+
+    let mut vertices: Vec<&Vertex> = g.get_vertices().iter().collect();
+    vertices.sort();
+
+    for u in &vertices {
+        let (distances, _) = dijkstra(g, u);
+        for v in &vertices {
+            if u < v {
+                let d = distances
+                    .get(v)
+                    .unwrap_or_else(|| panic!("Vertex {} is not reachable from {}", v, u));
+                closure.add_edge(**u, **v, *d);
+            }
+        }
+    }
+
+    closure
+}
+
+/// Every pair of distinct vertices is joined by an edge.
+pub fn is_complete(g: &dyn Graph<Vertex, Weight>) -> bool {
+    let n = g.get_vertices().len();
+
+    g.get_vertices().iter().all(|u| {
+        let adj = g._get_adj_list(u).unwrap();
+        adj.len() - adj.contains_key(u) as usize == n - 1
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metric_closure_path() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 4);
+        g.add_edge(3, 4, 5);
+        g.add_edge(1, 4, 20);
+
+        let current = metric_closure(&g);
+        assert!(is_complete(&current));
+        assert_eq!(Some(&3), current.get_weight(&1, &2));
+        assert_eq!(Some(&7), current.get_weight(&1, &3));
+        assert_eq!(Some(&12), current.get_weight(&1, &4));
+        assert_eq!(Some(&9), current.get_weight(&2, &4));
+        assert_eq!(6, current.get_edges().len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn metric_closure_not_connected() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(3, 4, 4);
+
+        metric_closure(&g);
+    }
+
+    #[test]
+    fn complete_graph() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 3, 1);
+        assert!(!is_complete(&g));
+
+        g.add_edge(1, 3, 1);
+        assert!(is_complete(&g));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Tour {
+    pub vertices: Vec<Vertex>,
+    pub cost: Weight,
+}
+
+impl Tour {
+    /// Closed tour through `vertices` in order, returning to the first one.
+    pub fn from(g: &dyn Graph<Vertex, Weight>, vertices: Vec<Vertex>) -> Tour {
+        let cost = if vertices.len() < 2 {
+            0
+        } else {
+            (0..vertices.len())
+                .map(|i| {
+                    let (u, v) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+                    *g.get_weight(&u, &v)
+                        .unwrap_or_else(|| panic!("Missing edge ({}, {})", u, v))
+                })
+                .sum()
+        };
+
+        Tour { vertices, cost }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    #[test]
+    fn tour_cost() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 4);
+        g.add_edge(3, 1, 5);
+
+        assert_eq!(12, Tour::from(&g, vec![1, 2, 3]).cost);
+        assert_eq!(6, Tour::from(&g, vec![1, 2]).cost);
+        assert_eq!(0, Tour::from(&g, vec![1]).cost);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tour_missing_edge() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 3);
+        g.add_edge(2, 3, 4);
+
+        Tour::from(&g, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod approximation;
+pub mod exact;
+pub mod is_vertex_cover;
+pub mod pricing;
@@ -0,0 +1,78 @@
+use std::collections::HashSet;
+
+use crate::graph::{
+    graph::{Edge, Graph},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> HashSet<Vertex> {
+    approximation(g)
+}
+
+/// Both endpoints of a maximal matching: at most twice the minimum cover,
+/// since any cover needs one endpoint of every matched edge.
+pub fn approximation(g: &dyn Graph<Vertex, Weight>) -> HashSet<Vertex> {
+    let mut edges: Vec<&Edge<Vertex, Weight>> = g.get_edges().iter().collect();
+    edges.sort();
+
+    let mut cover = HashSet::new();
+    for (u, v, _) in edges {
+        if !cover.contains(u) && !cover.contains(v) {
+            cover.insert(*u);
+            cover.insert(*v);
+        }
+    }
+
+    cover
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::vertex_cover::is_vertex_cover::is_vertex_cover,
+        graph::undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn approximation_star_graph() {
+        let mut g = UndirectedGraph::new();
+
+        for v in 1..6 {
+            g.add_edge(0, v, 1);
+        }
+
+        let expected = HashSet::from([0, 1]);
+        let current = approximation(&g);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn approximation_within_factor_two() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 5, 1);
+        g.add_edge(5, 6, 1);
+        g.add_edge(6, 1, 1);
+        g.add_edge(1, 4, 1);
+
+        let current = approximation(&g);
+        assert!(is_vertex_cover(&g, &current));
+        assert!(current.len() <= 2 * 3);
+    }
+
+    #[test]
+    fn approximation_self_loop() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 1, 1);
+        g.add_edge(2, 3, 1);
+
+        let current = approximation(&g);
+        assert!(is_vertex_cover(&g, &current));
+        assert!(current.contains(&1));
+    }
+}
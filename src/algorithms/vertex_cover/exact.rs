@@ -0,0 +1,204 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    algorithms::vertex_cover::approximation::approximation,
+    graph::{
+        graph::Graph,
+        undirected_graph::{Vertex, Weight},
+    },
+};
+
+type Neighbourhoods = HashMap<Vertex, HashSet<Vertex>>;
+
+pub fn run(g: &dyn Graph<Vertex, Weight>) -> HashSet<Vertex> {
+    minimum_vertex_cover(g)
+}
+
+/// Tries every size from the matching lower bound up to the size of the
+/// 2-approximation, so the first cover found is a minimum one.
+pub fn minimum_vertex_cover(g: &dyn Graph<Vertex, Weight>) -> HashSet<Vertex> {
+    let upper_bound = approximation(g);
+
+    (upper_bound.len() / 2..upper_bound.len())
+        .find_map(|k| vertex_cover_of_size(g, k))
+        .unwrap_or(upper_bound)
+}
+
+/// A vertex cover with at most `k` vertices, if there is one. The instance is
+/// first shrunk with Buss' kernelization, then a bounded search tree branches
+/// on a vertex of maximum degree: either it or all of its neighbours are in
+/// the cover.
+pub fn vertex_cover_of_size(g: &dyn Graph<Vertex, Weight>, k: usize) -> Option<HashSet<Vertex>> {
+    let neighbourhoods: Neighbourhoods = g
+        .get_vertices()
+        .iter()
+        .map(|u| (*u, g._get_adj_list(u).unwrap().keys().cloned().collect()))
+        .collect();
+
+    search(neighbourhoods, k, HashSet::new())
+}
+
+fn search(
+    mut neighbourhoods: Neighbourhoods,
+    mut k: usize,
+    mut cover: HashSet<Vertex>,
+) -> Option<HashSet<Vertex>> {
+    loop {
+        // A vertex with a self-loop or more than k neighbours must be taken,
+        // and so must the neighbour of a vertex of degree one.
+        let forced = neighbourhoods
+            .iter()
+            .find(|(v, n)| n.contains(v) || n.len() > k)
+            .map(|(v, _)| *v)
+            .or_else(|| {
+                neighbourhoods
+                    .values()
+                    .find(|n| n.len() == 1)
+                    .map(|n| *n.iter().next().unwrap())
+            });
+
+        match forced {
+            Some(_) if k == 0 => return None,
+            Some(v) => {
+                take(&mut neighbourhoods, &v, &mut cover);
+                k -= 1;
+            }
+            None => break,
+        }
+    }
+
+    let edges = neighbourhoods.values().map(|n| n.len()).sum::<usize>() / 2;
+    if edges == 0 {
+        return Some(cover);
+    }
+    if k == 0 || edges > k * k {
+        return None;
+    }
+
+    let (u, neighbours) = neighbourhoods
+        .iter()
+        .max_by_key(|(v, n)| (n.len(), std::cmp::Reverse(**v)))
+        .map(|(v, n)| (*v, n.clone()))
+        .unwrap();
+
+    let mut with_u = neighbourhoods.clone();
+    let mut cover_with_u = cover.clone();
+    take(&mut with_u, &u, &mut cover_with_u);
+    if let Some(cover) = search(with_u, k - 1, cover_with_u) {
+        return Some(cover);
+    }
+
+    if neighbours.len() > k {
+        return None;
+    }
+    for v in &neighbours {
+        take(&mut neighbourhoods, v, &mut cover);
+    }
+    search(neighbourhoods, k - neighbours.len(), cover)
+}
+
+fn take(neighbourhoods: &mut Neighbourhoods, v: &Vertex, cover: &mut HashSet<Vertex>) {
+    cover.insert(*v);
+
+    for u in neighbourhoods.remove(v).unwrap_or_default() {
+        if let Some(n) = neighbourhoods.get_mut(&u) {
+            n.remove(v);
+            if n.is_empty() {
+                neighbourhoods.remove(&u);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::vertex_cover::is_vertex_cover::is_vertex_cover,
+        graph::generators::{
+            families::{petersen, WeightPolicy},
+            rng::Rng,
+            testing::random_edges,
+        },
+        graph::undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn vertex_cover_of_size_petersen_graph() {
+        let g = petersen(&WeightPolicy::Unit);
+
+        assert_eq!(None, vertex_cover_of_size(&g, 5));
+
+        let current = vertex_cover_of_size(&g, 6).unwrap();
+        assert!(is_vertex_cover(&g, &current));
+        assert!(current.len() <= 6);
+    }
+
+    #[test]
+    fn minimum_vertex_cover_path() {
+        let mut g = UndirectedGraph::new();
+
+        for v in 1..7 {
+            g.add_edge(v, v + 1, 1);
+        }
+
+        let current = minimum_vertex_cover(&g);
+        assert!(is_vertex_cover(&g, &current));
+        assert_eq!(3, current.len());
+    }
+
+    #[test]
+    fn minimum_vertex_cover_complete_graph() {
+        let mut g = UndirectedGraph::new();
+
+        for u in 0..6 {
+            for v in u + 1..6 {
+                g.add_edge(u, v, 1);
+            }
+        }
+
+        let current = minimum_vertex_cover(&g);
+        assert!(is_vertex_cover(&g, &current));
+        assert_eq!(5, current.len());
+    }
+
+    #[test]
+    fn minimum_vertex_cover_matches_brute_force() {
+        let mut rng = Rng::new(11);
+        for _ in 0..10 {
+            let mut g = UndirectedGraph::new();
+            random_edges(&mut g, 9, 10, &(1..=1), &mut rng);
+
+            let vertices: Vec<Vertex> = g.get_vertices().iter().cloned().collect();
+            let minimum = (0..1u32 << vertices.len())
+                .filter(|mask| {
+                    let cover = (0..vertices.len())
+                        .filter(|i| mask & (1 << i) != 0)
+                        .map(|i| vertices[i])
+                        .collect();
+                    is_vertex_cover(&g, &cover)
+                })
+                .map(|mask| mask.count_ones() as usize)
+                .min()
+                .unwrap();
+
+            let current = minimum_vertex_cover(&g);
+            assert!(is_vertex_cover(&g, &current));
+            assert_eq!(minimum, current.len());
+        }
+    }
+
+    #[test]
+    fn minimum_vertex_cover_self_loop() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 1, 1);
+        g.add_edge(1, 2, 1);
+        g.add_edge(3, 4, 1);
+
+        let current = minimum_vertex_cover(&g);
+        assert!(is_vertex_cover(&g, &current));
+        assert_eq!(2, current.len());
+        assert!(current.contains(&1));
+    }
+}
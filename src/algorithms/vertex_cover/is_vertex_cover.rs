@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use crate::graph::{
+    graph::Graph,
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, cover: &HashSet<Vertex>) -> bool {
+    is_vertex_cover(g, cover)
+}
+
+pub fn is_vertex_cover(g: &dyn Graph<Vertex, Weight>, cover: &HashSet<Vertex>) -> bool {
+    g.get_edges()
+        .iter()
+        .all(|(u, v, _)| cover.contains(u) || cover.contains(v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::undirected_graph::UndirectedGraph;
+
+    #[test]
+    fn vertex_cover() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+
+        assert!(is_vertex_cover(&g, &HashSet::from([2, 3])));
+        assert!(is_vertex_cover(&g, &HashSet::from([1, 2, 4])));
+    }
+
+    #[test]
+    fn not_vertex_cover() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+
+        assert!(!is_vertex_cover(&g, &HashSet::from([1, 4])));
+        assert!(!is_vertex_cover(&g, &HashSet::new()));
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::{
+    graph::{Edge, Graph},
+    undirected_graph::{Vertex, Weight},
+};
+
+pub fn run(g: &dyn Graph<Vertex, Weight>, weights: &HashMap<Vertex, Weight>) -> HashSet<Vertex> {
+    pricing(g, weights)
+}
+
+/// Weighted vertex cover by the pricing method: every edge pays as much as
+/// its endpoints can still afford, and the vertices whose weight is fully
+/// paid form the cover. Its weight is at most twice the optimum.
+pub fn pricing(
+    g: &dyn Graph<Vertex, Weight>,
+    weights: &HashMap<Vertex, Weight>,
+) -> HashSet<Vertex> {
+    let mut slacks: HashMap<Vertex, Weight> = g
+        .get_vertices()
+        .iter()
+        .map(|v| {
+            let w = *weights
+                .get(v)
+                .unwrap_or_else(|| panic!("Missing weight of vertex {}", v));
+            if w < 0 {
+                panic!("Negative weight of vertex {}", v);
+            }
+            (*v, w)
+        })
+        .collect();
+
+    let mut edges: Vec<&Edge<Vertex, Weight>> = g.get_edges().iter().collect();
+    edges.sort();
+
+    for (u, v, _) in edges {
+        let price = slacks[u].min(slacks[v]);
+        *slacks.get_mut(u).unwrap() -= price;
+        if u != v {
+            *slacks.get_mut(v).unwrap() -= price;
+        }
+    }
+
+    slacks
+        .into_iter()
+        .filter(|(_, slack)| *slack == 0)
+        .map(|(v, _)| v)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorithms::vertex_cover::is_vertex_cover::is_vertex_cover,
+        graph::undirected_graph::UndirectedGraph,
+    };
+
+    #[test]
+    fn pricing_star_graph_with_heavy_center() {
+        let mut g = UndirectedGraph::new();
+
+        for v in 1..4 {
+            g.add_edge(0, v, 1);
+        }
+        let weights = HashMap::from([(0, 10), (1, 1), (2, 1), (3, 1)]);
+
+        let expected = HashSet::from([1, 2, 3]);
+        let current = pricing(&g, &weights);
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn pricing_star_graph_with_light_center() {
+        let mut g = UndirectedGraph::new();
+
+        for v in 1..4 {
+            g.add_edge(0, v, 1);
+        }
+        let weights = HashMap::from([(0, 2), (1, 1), (2, 1), (3, 1)]);
+
+        let current = pricing(&g, &weights);
+        assert!(is_vertex_cover(&g, &current));
+        assert!(current.iter().map(|v| weights[v]).sum::<Weight>() <= 2 * 2);
+    }
+
+    #[test]
+    fn pricing_within_factor_two() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 3, 1);
+        g.add_edge(3, 4, 1);
+        g.add_edge(4, 1, 1);
+        g.add_edge(1, 3, 1);
+        let weights = HashMap::from([(1, 3), (2, 1), (3, 4), (4, 2)]);
+
+        // The optimum is {1, 3} with weight 7
+        let current = pricing(&g, &weights);
+        assert!(is_vertex_cover(&g, &current));
+        assert!(current.iter().map(|v| weights[v]).sum::<Weight>() <= 2 * 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn pricing_missing_weight() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(1, 2, 1);
+
+        pricing(&g, &HashMap::from([(1, 1)]));
+    }
+}
@@ -1 +1,5 @@
 pub mod cli;
+pub mod dimacs;
+pub mod dot;
+pub mod matrix;
+pub mod tsplib;
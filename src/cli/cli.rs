@@ -1,27 +1,156 @@
 use clap::Parser;
 use mockall::automock;
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::time::Duration;
 
+use crate::algorithms::bipartite::is_bipartite::Bipartiteness;
+use crate::algorithms::coloring::greedy::VertexOrder;
+use crate::algorithms::coloring::proper_coloring::Coloring;
 use crate::algorithms::connected_components::count_connected_components::count_connected_components;
-use crate::algorithms::{connected_components, cycles, minimum_spanning_tree};
-use crate::graph::graph::Graph;
+use crate::algorithms::cycles::simple_cycles::{CycleLimits, Cycles};
+use crate::algorithms::euler::chinese_postman::PostmanRoute;
+use crate::algorithms::euler::EulerError;
+use crate::algorithms::flow::MaxFlow;
+use crate::algorithms::matching::hopcroft_karp::BipartiteMatching;
+use crate::algorithms::matching::hungarian::{Assignment, Objective};
+use crate::algorithms::shortest_paths::all_pairs::AllPairs;
+use crate::algorithms::shortest_paths::astar::{
+    AStarResult, Heuristic, LandmarkHeuristic, ZeroHeuristic,
+};
+use crate::algorithms::topological_sort::CycleError;
+use crate::algorithms::tsp::metric_closure::{is_complete, metric_closure};
+use crate::algorithms::tsp::tour::Tour;
+use crate::algorithms::{
+    bipartite, coloring, connected_components, connectivity, cycles, euler, flow, matching,
+    minimum_spanning_tree, shortest_paths, topological_sort, tsp, vertex_cover,
+};
+use crate::cli::dimacs::{export_dimacs, read_dimacs, Problem, ProblemKind};
+use crate::cli::dot::{export_dot, Overlay};
+use crate::cli::matrix::{matrix_graph, read_matrix};
+use crate::cli::tsplib::read_tsplib;
+use crate::graph::directed_graph::DirectedGraph;
+use crate::graph::generators::random;
+use crate::graph::generators::rng::Rng;
+use crate::graph::graph::{Graph, Path};
 use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
 
-#[derive(clap::ValueEnum, Clone, Debug)]
+#[derive(clap::ValueEnum, Clone, Debug, Default)]
 pub enum Algorithm {
+    #[default]
     IsAcyclic,
+    IsBipartite,
     KruskalNaive,
     KruskalUnionFind,
     Prim,
     CountConnectedComponents,
+    StConnected,
+    AllCycles,
+    AStar,
+    Coloring,
+    VertexCover,
+    MinimumVertexCover,
+    TspDoubleTree,
+    TspChristofides,
+    TspHeldKarp,
+    TspLocalSearch,
+    FloydWarshall,
+    Johnson,
+    Bridges,
+    ArticulationPoints,
+    StronglyConnectedComponents,
+    WeaklyConnectedComponents,
+    TopologicalSort,
+    EdmondsKarp,
+    Dinic,
+    PushRelabel,
+    HopcroftKarp,
+    Hungarian,
+    EulerianCircuit,
+    EulerianPath,
+    ChinesePostman,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum ColoringStrategy {
+    Natural,
+    LargestFirst,
+    SmallestLast,
+    Dsatur,
+    Exact,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum TopologicalOrder {
+    Lexicographic,
+    Kahn,
+    Dfs,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum InputFormat {
+    EdgeList,
+    Tsplib,
+    Dimacs,
+    Matrix,
 }
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub args: Option<Args>,
+}
+
+#[derive(clap::Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Write a random graph in the edge list format
+    Generate(GenerateArgs),
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum RandomModel {
+    Gnp,
+    Gnm,
+    Connected,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct GenerateArgs {
+    #[arg(long, value_enum)]
+    pub model: RandomModel,
+
+    #[arg(short = 'n', long)]
+    pub vertices: usize,
+
+    #[arg(short = 'm', long, default_value=None)]
+    pub edges: Option<usize>,
+
+    #[arg(short, long, default_value=None)]
+    pub probability: Option<f64>,
+
+    #[arg(long, default_value_t = -10000, allow_negative_numbers = true)]
+    pub min_weight: Weight,
+
+    #[arg(long, default_value_t = 10000, allow_negative_numbers = true)]
+    pub max_weight: Weight,
+
+    #[arg(long, default_value_t = 0)]
+    pub seed: u64,
+
+    #[arg(short, long, default_value=None)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Parser, Debug, Clone, Default)]
 pub struct Args {
     #[arg(short, long, value_enum)]
     pub algorithm: Algorithm,
@@ -31,30 +160,85 @@ pub struct Args {
 
     #[arg(short, long, default_value=None)]
     pub start: Option<Vertex>,
+
+    #[arg(short, long, default_value=None)]
+    pub target: Option<Vertex>,
+
+    #[arg(short, long, default_value_t = false)]
+    pub directed: bool,
+
+    #[arg(long, default_value=None)]
+    pub max_length: Option<usize>,
+
+    #[arg(long, default_value=None)]
+    pub max_count: Option<usize>,
+
+    #[arg(long, default_value=None)]
+    pub landmarks: Option<usize>,
+
+    #[arg(long, value_enum, default_value=None)]
+    pub coloring: Option<ColoringStrategy>,
+
+    #[arg(long, value_enum, default_value=None)]
+    pub topological_order: Option<TopologicalOrder>,
+
+    #[arg(long, default_value=None)]
+    pub time_limit: Option<u64>,
+
+    #[arg(long, value_enum, default_value=None)]
+    pub input_format: Option<InputFormat>,
+
+    #[arg(long, default_value=None)]
+    pub export_dot: Option<PathBuf>,
+
+    #[arg(long, default_value=None)]
+    pub export_csv: Option<PathBuf>,
+
+    #[arg(long, default_value=None)]
+    pub export_dimacs: Option<PathBuf>,
+
+    #[arg(long, default_value_t = false)]
+    pub maximize: bool,
 }
 
 pub fn run_cli(args: &Args) -> Box<dyn Any> {
-    match args.algorithm {
+    let args = &with_direction(args);
+    let (mut dimacs, problem) = match args.input_format {
+        Some(InputFormat::Dimacs) => {
+            let (g, problem) = read_dimacs(&args.file, args.directed);
+            (Some(g), Some(problem))
+        }
+        _ => (None, None),
+    };
+    let args = &with_dimacs_terminals(args, problem.as_ref());
+
+    let res: Box<dyn Any> = match args.algorithm {
         Algorithm::IsAcyclic => {
-            let g = read_graph(&args.file);
+            let g = read_input(args, &mut dimacs);
             let res = cycles::is_acyclic::run(g.deref());
 
             Box::new(res)
         }
+        Algorithm::IsBipartite => {
+            let g = read_input(args, &mut dimacs);
+            let res = bipartite::is_bipartite::run(g.deref());
+
+            Box::new(res)
+        }
         Algorithm::KruskalNaive => {
-            let g: Box<dyn Graph<Vertex, Weight>> = read_graph(&args.file);
+            let g: Box<dyn Graph<Vertex, Weight>> = read_input(args, &mut dimacs);
             let path = minimum_spanning_tree::kruskal_naive::run(g.deref());
 
             Box::new(path)
         }
         Algorithm::KruskalUnionFind => {
-            let g = read_graph(&args.file);
+            let g = read_input(args, &mut dimacs);
             let path = minimum_spanning_tree::kruskal_union_find::run(g.deref());
 
             Box::new(path)
         }
         Algorithm::Prim => {
-            let g = read_graph(&args.file);
+            let g = read_input(args, &mut dimacs);
             let start = args
                 .start
                 .unwrap_or_else(|| panic!("Missing starting vertex"));
@@ -63,16 +247,515 @@ pub fn run_cli(args: &Args) -> Box<dyn Any> {
             Box::new(path)
         }
         Algorithm::CountConnectedComponents => {
-            let g = read_graph(&args.file);
+            let g = read_input(args, &mut dimacs);
             let connected_components =
                 connected_components::count_connected_components::run(g.deref());
 
             Box::new(connected_components)
         }
+        Algorithm::StConnected => {
+            let g = read_input(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let target = args
+                .target
+                .unwrap_or_else(|| panic!("Missing target vertex"));
+            let path = connectivity::st_connected::run(g.deref(), &start, &target);
+
+            Box::new(path)
+        }
+        Algorithm::AllCycles => {
+            let g = read_input(args, &mut dimacs);
+            let limits = CycleLimits {
+                max_length: args.max_length,
+                max_count: args.max_count,
+            };
+            let cycles = cycles::simple_cycles::run(g.deref(), limits);
+
+            Box::new(cycles)
+        }
+        Algorithm::AStar => {
+            let g = read_input(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let target = args
+                .target
+                .unwrap_or_else(|| panic!("Missing target vertex"));
+            let heuristic: Box<dyn Heuristic<Vertex, Weight>> = match args.landmarks {
+                Some(count) => Box::new(LandmarkHeuristic::new(g.deref(), count)),
+                None => Box::new(ZeroHeuristic),
+            };
+            let res = shortest_paths::astar::run(g.deref(), &start, &target, heuristic.deref());
+
+            Box::new(res)
+        }
+        Algorithm::Coloring => {
+            let g = read_input(args, &mut dimacs);
+            let coloring = match args.coloring.clone().unwrap_or(ColoringStrategy::Dsatur) {
+                ColoringStrategy::Natural => coloring::greedy::run(g.deref(), VertexOrder::Natural),
+                ColoringStrategy::LargestFirst => {
+                    coloring::greedy::run(g.deref(), VertexOrder::LargestFirst)
+                }
+                ColoringStrategy::SmallestLast => {
+                    coloring::greedy::run(g.deref(), VertexOrder::SmallestLast)
+                }
+                ColoringStrategy::Dsatur => coloring::dsatur::run(g.deref()),
+                ColoringStrategy::Exact => coloring::exact::run(g.deref()),
+            };
+
+            Box::new(coloring)
+        }
+        Algorithm::VertexCover => {
+            let g = read_input(args, &mut dimacs);
+            let cover = vertex_cover::approximation::run(g.deref());
+
+            Box::new(cover)
+        }
+        Algorithm::MinimumVertexCover => {
+            let g = read_input(args, &mut dimacs);
+            let cover = vertex_cover::exact::run(g.deref());
+
+            Box::new(cover)
+        }
+        Algorithm::TspDoubleTree => {
+            let g = read_complete_graph(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let tour = tsp::double_tree::run(g.deref(), &start);
+
+            Box::new(tour)
+        }
+        Algorithm::TspChristofides => {
+            let g = read_complete_graph(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let tour = tsp::christofides::run(g.deref(), &start);
+
+            Box::new(tour)
+        }
+        Algorithm::TspHeldKarp => {
+            let g = read_input(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let tour = tsp::held_karp::run(g.deref(), &start);
+
+            Box::new(tour)
+        }
+        Algorithm::TspLocalSearch => {
+            let g = read_complete_graph(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let tour = tsp::double_tree::run(g.deref(), &start);
+            let time_limit = args.time_limit.map(Duration::from_secs);
+            let tour = tsp::local_search::run(g.deref(), &tour, time_limit);
+
+            Box::new(tour)
+        }
+        Algorithm::FloydWarshall | Algorithm::Johnson => {
+            let g = read_input(args, &mut dimacs);
+            let res = match args.algorithm {
+                Algorithm::FloydWarshall => {
+                    shortest_paths::all_pairs::run_floyd_warshall(g.deref())
+                }
+                _ => shortest_paths::all_pairs::run_johnson(g.deref()),
+            };
+
+            if let (Some(path), AllPairs::Distances(m)) = (&args.export_csv, &res) {
+                fs::write(path, m.to_csv()).unwrap();
+            }
+
+            Box::new(res)
+        }
+        Algorithm::Bridges => {
+            let g = read_input(args, &mut dimacs);
+            let bridges = connectivity::bridges::run(g.deref());
+
+            Box::new(bridges)
+        }
+        Algorithm::ArticulationPoints => {
+            let g = read_input(args, &mut dimacs);
+            let points = connectivity::articulation_points::run(g.deref());
+
+            Box::new(points)
+        }
+        Algorithm::StronglyConnectedComponents => {
+            let g = read_input(args, &mut dimacs);
+            let labels = connected_components::strongly_connected_components::run(g.deref());
+
+            Box::new(labels)
+        }
+        Algorithm::WeaklyConnectedComponents => {
+            let g = read_input(args, &mut dimacs);
+            let labels = connected_components::weakly_connected_components::run(g.deref());
+
+            Box::new(labels)
+        }
+        Algorithm::TopologicalSort => {
+            let g = read_input(args, &mut dimacs);
+            let order = match args
+                .topological_order
+                .clone()
+                .unwrap_or(TopologicalOrder::Lexicographic)
+            {
+                TopologicalOrder::Lexicographic => topological_sort::kahn::run(g.deref(), true),
+                TopologicalOrder::Kahn => topological_sort::kahn::run(g.deref(), false),
+                TopologicalOrder::Dfs => topological_sort::dfs::run(g.deref()),
+            };
+
+            Box::new(order)
+        }
+        Algorithm::EdmondsKarp | Algorithm::Dinic | Algorithm::PushRelabel => {
+            let g = read_input(args, &mut dimacs);
+            let start = args
+                .start
+                .unwrap_or_else(|| panic!("Missing starting vertex"));
+            let target = args
+                .target
+                .unwrap_or_else(|| panic!("Missing target vertex"));
+            let res = match args.algorithm {
+                Algorithm::EdmondsKarp => flow::edmonds_karp::run(g.deref(), &start, &target),
+                Algorithm::Dinic => flow::dinic::run(g.deref(), &start, &target),
+                _ => flow::push_relabel::run(g.deref(), &start, &target),
+            };
+
+            Box::new(res)
+        }
+        Algorithm::HopcroftKarp => {
+            let g = read_input(args, &mut dimacs);
+            let res = matching::hopcroft_karp::run(g.deref());
+
+            Box::new(res)
+        }
+        Algorithm::Hungarian => {
+            let objective = if args.maximize {
+                Objective::Maximize
+            } else {
+                Objective::Minimize
+            };
+            let res = match args.input_format {
+                Some(InputFormat::Matrix) => {
+                    matching::hungarian::run(&read_matrix(&args.file), objective)
+                }
+                _ => {
+                    let g = read_input(args, &mut dimacs);
+                    matching::hungarian::hungarian_graph(g.deref(), objective)
+                }
+            };
+
+            Box::new(res)
+        }
+        Algorithm::EulerianCircuit => {
+            let g = read_input(args, &mut dimacs);
+            let res = euler::hierholzer::eulerian_circuit(g.deref());
+
+            Box::new(res)
+        }
+        Algorithm::EulerianPath => {
+            let g = read_input(args, &mut dimacs);
+            let res = euler::hierholzer::run(g.deref());
+
+            Box::new(res)
+        }
+        Algorithm::ChinesePostman => {
+            let g = read_input(args, &mut dimacs);
+            let res = euler::chinese_postman::run(g.deref());
+
+            Box::new(res)
+        }
+    };
+
+    if let Some(path) = &args.export_dimacs {
+        let g = read_input(args, &mut dimacs);
+        export_dimacs(path, g.deref(), &dimacs_problem(args, g.deref(), problem));
+    }
+
+    match &args.export_dot {
+        Some(path) => export_overlay(args, path, res, &mut dimacs),
+        None => res,
+    }
+}
+
+/// Writes the graph as DOT with the result of the algorithm drawn on top.
+/// Cycles are streamed, so they are collected first and handed back as a new
+/// iterator.
+fn export_overlay(
+    args: &Args,
+    path: &PathBuf,
+    mut res: Box<dyn Any>,
+    dimacs: &mut Option<Box<dyn Graph<Vertex, Weight>>>,
+) -> Box<dyn Any> {
+    let g = match args.algorithm {
+        Algorithm::TspDoubleTree | Algorithm::TspChristofides | Algorithm::TspLocalSearch => {
+            read_complete_graph(args, dimacs)
+        }
+        _ => read_input(args, dimacs),
+    };
+    let mut overlay = Overlay::default();
+
+    match args.algorithm {
+        Algorithm::IsAcyclic => {}
+        Algorithm::IsBipartite => match res.downcast_ref::<Bipartiteness>().unwrap() {
+            Bipartiteness::Bipartite(left, right) => {
+                overlay.vertex_groups = left
+                    .iter()
+                    .map(|v| (*v, 0))
+                    .chain(right.iter().map(|v| (*v, 1)))
+                    .collect();
+            }
+            Bipartiteness::OddCycle(cycle) => overlay.highlighted_edges = edge_pairs(cycle),
+        },
+        Algorithm::KruskalNaive | Algorithm::KruskalUnionFind | Algorithm::Prim => {
+            let path = res.downcast_ref::<Path<Vertex, Weight>>().unwrap();
+            overlay.bold_edges = edge_pairs(path);
+        }
+        Algorithm::Bridges => {
+            let bridges = res.downcast_ref::<Path<Vertex, Weight>>().unwrap();
+            overlay.highlighted_edges = edge_pairs(bridges);
+        }
+        Algorithm::EdmondsKarp | Algorithm::Dinic | Algorithm::PushRelabel => {
+            let res = res.downcast_ref::<MaxFlow>().unwrap();
+            overlay.bold_edges = res
+                .flow
+                .iter()
+                .filter(|e| e.2 > 0)
+                .map(|(u, v, _)| (*u, *v))
+                .collect();
+            overlay.highlighted_edges = edge_pairs(&res.cut);
+            overlay.vertex_groups = res.source_side.iter().map(|v| (*v, 0)).collect();
+        }
+        Algorithm::HopcroftKarp => {
+            let res = res.downcast_ref::<BipartiteMatching>().unwrap();
+            overlay.bold_edges = edge_pairs(&res.matching);
+            overlay.vertex_groups = res.cover.iter().map(|v| (*v, 0)).collect();
+        }
+        Algorithm::Hungarian => {
+            let pairs = &res.downcast_ref::<Assignment>().unwrap().pairs;
+            overlay.bold_edges = match args.input_format {
+                Some(InputFormat::Matrix) => {
+                    let n = read_matrix(&args.file).len();
+                    pairs.iter().map(|(i, j)| (i + 1, n + j + 1)).collect()
+                }
+                _ => pairs.iter().cloned().collect(),
+            };
+        }
+        Algorithm::EulerianCircuit | Algorithm::EulerianPath => {
+            match res
+                .downcast_ref::<Result<Path<Vertex, Weight>, EulerError>>()
+                .unwrap()
+            {
+                Ok(trail) => overlay.highlighted_edges = edge_pairs(trail),
+                Err(EulerError::OddDegree(vertices)) => {
+                    overlay.vertex_groups = vertices.iter().map(|v| (*v, 0)).collect()
+                }
+                Err(EulerError::Unbalanced(balance)) => {
+                    overlay.vertex_groups = balance.iter().map(|(v, _)| (*v, 0)).collect()
+                }
+                Err(EulerError::Disconnected(components)) => {
+                    overlay.vertex_groups = components
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(i, c)| c.iter().map(move |v| (*v, i)))
+                        .collect()
+                }
+            }
+        }
+        Algorithm::ChinesePostman => {
+            if let Some(route) = res.downcast_ref::<Option<PostmanRoute>>().unwrap() {
+                overlay.highlighted_edges = edge_pairs(&route.circuit);
+            }
+        }
+        Algorithm::TopologicalSort => {
+            if let Err(e) = res
+                .downcast_ref::<Result<Vec<Vertex>, CycleError>>()
+                .unwrap()
+            {
+                overlay.highlighted_edges = edge_pairs(&e.cycle);
+            }
+        }
+        Algorithm::CountConnectedComponents => {
+            overlay.vertex_groups =
+                connected_components::label_connected_components::run(g.deref());
+        }
+        Algorithm::StronglyConnectedComponents | Algorithm::WeaklyConnectedComponents => {
+            overlay.vertex_groups = res
+                .downcast_ref::<HashMap<Vertex, usize>>()
+                .unwrap()
+                .clone();
+        }
+        Algorithm::StConnected => {
+            if let Some(path) = res.downcast_ref::<Option<Path<Vertex, Weight>>>().unwrap() {
+                overlay.highlighted_edges = edge_pairs(path);
+            }
+        }
+        Algorithm::AllCycles => {
+            let cycles: Vec<Path<Vertex, Weight>> = res.downcast::<Cycles>().unwrap().collect();
+            overlay.highlighted_edges = cycles.iter().flat_map(edge_pairs).collect();
+
+            let cycles: Cycles = Box::new(cycles.into_iter());
+            res = Box::new(cycles);
+        }
+        Algorithm::AStar => {
+            if let Some(astar) = res.downcast_ref::<Option<AStarResult>>().unwrap() {
+                overlay.highlighted_edges = edge_pairs(&astar.path);
+            }
+        }
+        Algorithm::Coloring => {
+            overlay.vertex_groups = res.downcast_ref::<Coloring>().unwrap().colors.clone();
+        }
+        Algorithm::VertexCover | Algorithm::MinimumVertexCover | Algorithm::ArticulationPoints => {
+            let cover = res.downcast_ref::<HashSet<Vertex>>().unwrap();
+            overlay.vertex_groups = cover.iter().map(|v| (*v, 0)).collect();
+        }
+        Algorithm::TspDoubleTree
+        | Algorithm::TspChristofides
+        | Algorithm::TspHeldKarp
+        | Algorithm::TspLocalSearch => {
+            let tour = match res.downcast_ref::<Tour>() {
+                Some(tour) => Some(tour),
+                None => res.downcast_ref::<Option<Tour>>().unwrap().as_ref(),
+            };
+            if let Some(tour) = tour {
+                let n = tour.vertices.len();
+                overlay.highlighted_edges = (0..n)
+                    .map(|i| (tour.vertices[i], tour.vertices[(i + 1) % n]))
+                    .collect();
+            }
+        }
+        Algorithm::FloydWarshall | Algorithm::Johnson => {
+            if let AllPairs::NegativeCycle(cycle) = res.downcast_ref::<AllPairs>().unwrap() {
+                overlay.highlighted_edges = edge_pairs(cycle);
+            }
+        }
+    }
+
+    export_dot(path, g.deref(), &overlay);
+
+    res
+}
+
+fn edge_pairs(path: &Path<Vertex, Weight>) -> HashSet<(Vertex, Vertex)> {
+    path.iter().map(|(u, v, _)| (*u, *v)).collect()
+}
+
+/// Reads the graph of `args`, or takes the one already parsed from a DIMACS
+/// input.
+fn read_input(
+    args: &Args,
+    dimacs: &mut Option<Box<dyn Graph<Vertex, Weight>>>,
+) -> Box<dyn Graph<Vertex, Weight>> {
+    if let Some(g) = dimacs.take() {
+        return g;
+    }
+
+    match args.input_format.clone().unwrap_or(InputFormat::EdgeList) {
+        InputFormat::EdgeList => read_graph(&args.file, args.directed),
+        InputFormat::Tsplib => Box::new(read_tsplib(&args.file)),
+        InputFormat::Dimacs => read_dimacs(&args.file, args.directed).0,
+        InputFormat::Matrix => Box::new(matrix_graph(&read_matrix(&args.file))),
+    }
+}
+
+/// Topological sort is only defined on directed graphs, so its input is read
+/// as directed whatever `-d` says.
+fn with_direction(args: &Args) -> Args {
+    let mut args = args.clone();
+
+    if let Algorithm::TopologicalSort = args.algorithm {
+        args.directed = true;
+    }
+
+    args
+}
+
+/// The source and sink of a DIMACS max flow instance stand in for a missing
+/// starting and target vertex.
+fn with_dimacs_terminals(args: &Args, problem: Option<&Problem>) -> Args {
+    let mut args = args.clone();
+
+    if let Some(problem) = problem {
+        args.start = args.start.or(problem.source);
+        args.target = args.target.or(problem.sink);
+    }
+
+    args
+}
+
+/// Problem line for `--export-dimacs`: the one of a DIMACS input, else a max
+/// flow instance between the starting and target vertex for the flow
+/// algorithms, a shortest path one for other directed graphs and an edge one
+/// for undirected graphs.
+fn dimacs_problem(args: &Args, g: &dyn Graph<Vertex, Weight>, problem: Option<Problem>) -> Problem {
+    problem.unwrap_or_else(|| {
+        let kind = match args.algorithm {
+            Algorithm::EdmondsKarp | Algorithm::Dinic | Algorithm::PushRelabel => {
+                ProblemKind::MaxFlow
+            }
+            _ if g.is_directed() => ProblemKind::ShortestPath,
+            _ => ProblemKind::Edge,
+        };
+
+        Problem {
+            kind,
+            vertices: g.get_vertices().len(),
+            edges: g.get_edges().len(),
+            source: args.start,
+            sink: args.target,
+        }
+    })
+}
+
+/// Generates the graph described by `args` and returns it in the edge list
+/// format, writing it to the output file if there is one.
+pub fn run_generate(args: &GenerateArgs) -> String {
+    let weights = args.min_weight..=args.max_weight;
+    let mut rng = Rng::new(args.seed);
+    let edges = || {
+        args.edges
+            .unwrap_or_else(|| panic!("Missing number of edges"))
+    };
+
+    let g = match args.model {
+        RandomModel::Gnp => {
+            let p = args
+                .probability
+                .unwrap_or_else(|| panic!("Missing edge probability"));
+            random::gnp(args.vertices, p, &weights, &mut rng)
+        }
+        RandomModel::Gnm => random::gnm(args.vertices, edges(), &weights, &mut rng),
+        RandomModel::Connected => {
+            random::random_connected(args.vertices, edges(), &weights, &mut rng)
+        }
+    };
+    let out = write_graph(args.vertices, &g);
+
+    if let Some(path) = &args.output {
+        fs::write(path, &out).unwrap();
+    }
+
+    out
+}
+
+/// Edge list read by `read_graph`: a `n m` header, then one `u v w` line per
+/// edge, sorted.
+pub fn write_graph(n: usize, g: &dyn Graph<Vertex, Weight>) -> String {
+    let mut edges: Vec<_> = g.get_edges().iter().collect();
+    edges.sort();
+
+    let mut out = format!("{} {}\n", n, edges.len());
+    for (u, v, w) in edges {
+        out += &format!("{} {} {}\n", u, v, w);
     }
+
+    out
 }
 
-fn read_graph(path: &PathBuf) -> Box<dyn Graph<Vertex, Weight>> {
+fn read_graph(path: &PathBuf, directed: bool) -> Box<dyn Graph<Vertex, Weight>> {
     let lines = fs::read_to_string(path).unwrap();
     let mut lines = lines.lines();
     let mut header = lines
@@ -83,7 +766,11 @@ fn read_graph(path: &PathBuf) -> Box<dyn Graph<Vertex, Weight>> {
         .next()
         .map(|v| v.parse::<usize>().unwrap())
         .unwrap_or_else(|| panic!("Invalid format"));
-    let mut g = Box::new(UndirectedGraph::new());
+    let mut g: Box<dyn Graph<Vertex, Weight>> = if directed {
+        Box::new(DirectedGraph::new())
+    } else {
+        Box::new(UndirectedGraph::new())
+    };
 
     lines.for_each(|tmp| {
         let mut line = tmp.split_whitespace();
@@ -105,3 +792,16 @@ fn read_graph(path: &PathBuf) -> Box<dyn Graph<Vertex, Weight>> {
 
     return g;
 }
+
+fn read_complete_graph(
+    args: &Args,
+    dimacs: &mut Option<Box<dyn Graph<Vertex, Weight>>>,
+) -> Box<dyn Graph<Vertex, Weight>> {
+    let g = read_input(args, dimacs);
+
+    if is_complete(g.deref()) {
+        g
+    } else {
+        Box::new(metric_closure(g.deref()))
+    }
+}
@@ -0,0 +1,307 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::graph::directed_graph::DirectedGraph;
+use crate::graph::graph::Graph;
+use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ProblemKind {
+    ShortestPath,
+    MaxFlow,
+    Edge,
+}
+
+/// Metadata from the problem line and, for max flow, the `n` lines naming
+/// the source and sink.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Problem {
+    pub kind: ProblemKind,
+    pub vertices: usize,
+    pub edges: usize,
+    pub source: Option<Vertex>,
+    pub sink: Option<Vertex>,
+}
+
+pub fn read_dimacs(path: &PathBuf, directed: bool) -> (Box<dyn Graph<Vertex, Weight>>, Problem) {
+    parse_dimacs(&fs::read_to_string(path).unwrap(), directed)
+}
+
+/// Reads `p sp`, `p max` and `p edge` (or `p col`) instances. Arcs of `a`
+/// lines carry their length or capacity as weight, `e` lines weigh 1 unless
+/// they have a third field. `sp` and `max` instances are always directed;
+/// `directed` only applies to edge instances. Parallel arcs keep the shortest
+/// length, or the sum of the capacities. The graph has no isolated vertices,
+/// so those counted by the problem line but on no arc or edge are only seen in
+/// `Problem::vertices`.
+pub fn parse_dimacs(input: &str, directed: bool) -> (Box<dyn Graph<Vertex, Weight>>, Problem) {
+    let mut g: Option<Box<dyn Graph<Vertex, Weight>>> = None;
+    let mut problem: Option<Problem> = None;
+
+    for line in input.lines() {
+        let mut fields = line.split_whitespace();
+
+        match fields.next() {
+            None | Some("c") => {}
+            Some("p") => {
+                let kind = match fields.next() {
+                    Some("sp") => ProblemKind::ShortestPath,
+                    Some("max") => ProblemKind::MaxFlow,
+                    Some("edge") | Some("col") => ProblemKind::Edge,
+                    _ => panic!("Unsupported DIMACS problem"),
+                };
+                let vertices = fields
+                    .next()
+                    .map(|n| n.parse::<usize>().unwrap())
+                    .unwrap_or_else(|| panic!("Invalid format"));
+                let edges = fields
+                    .next()
+                    .map(|m| m.parse::<usize>().unwrap())
+                    .unwrap_or_else(|| panic!("Invalid format"));
+
+                g = Some(if kind == ProblemKind::Edge && !directed {
+                    Box::new(UndirectedGraph::new())
+                } else {
+                    Box::new(DirectedGraph::new())
+                });
+                problem = Some(Problem {
+                    kind,
+                    vertices,
+                    edges,
+                    source: None,
+                    sink: None,
+                });
+            }
+            Some("n") => {
+                let problem = problem
+                    .as_mut()
+                    .unwrap_or_else(|| panic!("Missing problem line"));
+                let v = fields
+                    .next()
+                    .map(|v| v.parse::<Vertex>().unwrap())
+                    .unwrap_or_else(|| panic!("Invalid format"));
+
+                match fields.next() {
+                    Some("s") => problem.source = Some(v),
+                    Some("t") => problem.sink = Some(v),
+                    _ => panic!("Invalid format"),
+                }
+            }
+            Some(kind @ ("a" | "e")) => {
+                let (g, problem) = match (g.as_mut(), problem.as_ref()) {
+                    (Some(g), Some(problem)) => (g, problem),
+                    _ => panic!("Missing problem line"),
+                };
+                let u = fields
+                    .next()
+                    .map(|v| v.parse::<Vertex>().unwrap())
+                    .unwrap_or_else(|| panic!("Invalid format"));
+                let v = fields
+                    .next()
+                    .map(|v| v.parse::<Vertex>().unwrap())
+                    .unwrap_or_else(|| panic!("Invalid format"));
+                let w = match fields.next() {
+                    Some(w) => w.parse::<Weight>().unwrap(),
+                    None if kind == "e" => 1,
+                    None => panic!("Invalid format"),
+                };
+
+                let w = match (&problem.kind, g.get_weight(&u, &v)) {
+                    (ProblemKind::ShortestPath, Some(old)) => w.min(*old),
+                    (ProblemKind::MaxFlow, Some(old)) => w + old,
+                    _ => w,
+                };
+                g.add_edge(u, v, w);
+            }
+            _ => panic!("Invalid format"),
+        }
+    }
+
+    match (g, problem) {
+        (Some(g), Some(problem)) => (g, problem),
+        _ => panic!("Missing problem line"),
+    }
+}
+
+pub fn export_dimacs(path: &PathBuf, g: &dyn Graph<Vertex, Weight>, problem: &Problem) {
+    fs::write(path, write_dimacs(g, problem)).unwrap();
+}
+
+/// Writes `g` with the problem line of `problem`, whose vertex and edge counts
+/// are taken from the graph. Vertices must be numbered from 1; `e` lines have
+/// no weight, so it is lost for edge problems.
+pub fn write_dimacs(g: &dyn Graph<Vertex, Weight>, problem: &Problem) -> String {
+    if g.get_vertices().contains(&0) {
+        panic!("DIMACS vertices are numbered from 1");
+    }
+
+    let mut edges: Vec<_> = g.get_edges().iter().collect();
+    edges.sort();
+
+    let (kind, line) = match problem.kind {
+        ProblemKind::ShortestPath => ("sp", "a"),
+        ProblemKind::MaxFlow => ("max", "a"),
+        ProblemKind::Edge => ("edge", "e"),
+    };
+    let n = g.get_vertices().iter().max().copied().unwrap_or(0);
+    let mut out = format!("p {} {} {}\n", kind, n, edges.len());
+
+    if problem.kind == ProblemKind::MaxFlow {
+        if let Some(s) = problem.source {
+            out += &format!("n {} s\n", s);
+        }
+        if let Some(t) = problem.sink {
+            out += &format!("n {} t\n", t);
+        }
+    }
+    for (u, v, w) in edges {
+        out += &match problem.kind {
+            ProblemKind::Edge => format!("{} {} {}\n", line, u, v),
+            _ => format!("{} {} {} {}\n", line, u, v, w),
+        };
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortest_path_instance() {
+        let (g, problem) = parse_dimacs(
+            "c 9th DIMACS challenge
+p sp 3 3
+c arcs
+a 1 2 5
+a 2 3 -2
+a 3 1 7
+",
+            true,
+        );
+
+        let expected = Problem {
+            kind: ProblemKind::ShortestPath,
+            vertices: 3,
+            edges: 3,
+            source: None,
+            sink: None,
+        };
+        assert_eq!(expected, problem);
+        assert_eq!(3, g.get_edges().len());
+        assert_eq!(Some(&-2), g.get_weight(&2, &3));
+        assert_eq!(None, g.get_weight(&3, &2));
+    }
+
+    #[test]
+    fn max_flow_instance() {
+        let (g, problem) = parse_dimacs(
+            "p max 4 5
+n 1 s
+n 4 t
+a 1 2 3
+a 1 3 2
+a 2 3 1
+a 2 4 2
+a 3 4 3
+",
+            true,
+        );
+
+        assert_eq!(ProblemKind::MaxFlow, problem.kind);
+        assert_eq!(Some(1), problem.source);
+        assert_eq!(Some(4), problem.sink);
+        assert_eq!(5, g.get_edges().len());
+    }
+
+    #[test]
+    fn edge_instance() {
+        let (g, problem) = parse_dimacs(
+            "c coloring
+p col 3 2
+e 1 2
+e 3 2
+",
+            false,
+        );
+
+        assert_eq!(ProblemKind::Edge, problem.kind);
+        assert_eq!(Some(&1), g.get_weight(&2, &1));
+        assert_eq!(Some(&1), g.get_weight(&2, &3));
+    }
+
+    #[test]
+    fn arc_problems_are_directed() {
+        let (g, _) = parse_dimacs("p sp 2 1\na 1 2 5\n", false);
+        assert!(g.is_directed());
+        assert_eq!(None, g.get_weight(&2, &1));
+
+        let (g, _) = parse_dimacs("p max 2 1\na 1 2 5\n", false);
+        assert!(g.is_directed());
+
+        let (g, _) = parse_dimacs("p edge 2 1\ne 1 2\n", true);
+        assert!(g.is_directed());
+    }
+
+    #[test]
+    fn parallel_arcs() {
+        let (g, _) = parse_dimacs("p max 2 3\na 1 2 3\na 1 2 4\na 2 1 1\n", true);
+        assert_eq!(Some(&7), g.get_weight(&1, &2));
+        assert_eq!(Some(&1), g.get_weight(&2, &1));
+
+        let (g, _) = parse_dimacs("p sp 2 2\na 1 2 3\na 1 2 -4\n", true);
+        assert_eq!(Some(&-4), g.get_weight(&1, &2));
+    }
+
+    #[test]
+    fn isolated_vertices() {
+        let (g, problem) = parse_dimacs("p sp 5 1\na 1 2 3\n", true);
+
+        assert_eq!(5, problem.vertices);
+        assert_eq!(2, g.get_vertices().len());
+    }
+
+    #[test]
+    fn write_and_read_back() {
+        let input = "p max 4 3
+n 1 s
+n 4 t
+a 1 2 3
+a 2 4 2
+a 3 4 3
+";
+        let (g, problem) = parse_dimacs(input, true);
+
+        assert_eq!(input, write_dimacs(g.as_ref(), &problem));
+    }
+
+    #[test]
+    fn write_edge_instance() {
+        let mut g = UndirectedGraph::new();
+
+        g.add_edge(2, 1, 5);
+        g.add_edge(2, 3, 6);
+
+        let problem = Problem {
+            kind: ProblemKind::Edge,
+            vertices: 0,
+            edges: 0,
+            source: None,
+            sink: None,
+        };
+        assert_eq!("p edge 3 2\ne 1 2\ne 2 3\n", write_dimacs(&g, &problem));
+    }
+
+    #[test]
+    #[should_panic]
+    fn arc_before_problem_line() {
+        parse_dimacs("a 1 2 3\np sp 2 1\n", true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn arc_without_weight() {
+        parse_dimacs("p sp 2 1\na 1 2\n", true);
+    }
+}
@@ -0,0 +1,177 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::fs;
+use std::hash::Hash;
+use std::path::PathBuf;
+
+use crate::graph::graph::Graph;
+
+const PALETTE: [&str; 10] = [
+    "lightblue",
+    "lightcoral",
+    "palegreen",
+    "khaki",
+    "plum",
+    "lightsalmon",
+    "lightcyan",
+    "wheat",
+    "thistle",
+    "lightpink",
+];
+
+/// Result drawn over the graph: bold edges (a spanning tree), highlighted
+/// edges (a cycle, a path or a tour) and vertex groups filled with one color
+/// each (components, color classes).
+#[derive(Debug, Clone)]
+pub struct Overlay<V> {
+    pub bold_edges: HashSet<(V, V)>,
+    pub highlighted_edges: HashSet<(V, V)>,
+    pub vertex_groups: HashMap<V, usize>,
+}
+
+impl<V> Default for Overlay<V> {
+    fn default() -> Self {
+        Overlay {
+            bold_edges: HashSet::new(),
+            highlighted_edges: HashSet::new(),
+            vertex_groups: HashMap::new(),
+        }
+    }
+}
+
+pub fn export_dot<V, W>(path: &PathBuf, g: &dyn Graph<V, W>, overlay: &Overlay<V>)
+where
+    V: Display + Ord + Hash + Copy,
+    W: Display,
+{
+    fs::write(path, write_dot(g, overlay)).unwrap();
+}
+
+/// Graphviz source of `g` with `overlay` drawn on top. Edges of an undirected
+/// graph match the overlay in either direction.
+pub fn write_dot<V, W>(g: &dyn Graph<V, W>, overlay: &Overlay<V>) -> String
+where
+    V: Display + Ord + Hash + Copy,
+    W: Display,
+{
+    let directed = g.is_directed();
+    let contains = |edges: &HashSet<(V, V)>, u: V, v: V| {
+        edges.contains(&(u, v)) || !directed && edges.contains(&(v, u))
+    };
+
+    let mut out = String::from(if directed { "digraph {\n" } else { "graph {\n" });
+
+    let mut vertices: Vec<&V> = g.get_vertices().iter().collect();
+    vertices.sort();
+    for v in vertices {
+        match overlay.vertex_groups.get(v) {
+            Some(group) => {
+                out += &format!(
+                    "    {} [style=filled, fillcolor={}];\n",
+                    v,
+                    PALETTE[group % PALETTE.len()]
+                )
+            }
+            None => out += &format!("    {};\n", v),
+        }
+    }
+
+    let mut edges: Vec<_> = g.get_edges().iter().collect();
+    edges.sort_by_key(|(u, v, _)| (*u, *v));
+    for (u, v, w) in edges {
+        let mut attributes = vec![format!("label=\"{}\"", w)];
+        if contains(&overlay.bold_edges, *u, *v) {
+            attributes.push(String::from("style=bold, penwidth=3"));
+        }
+        if contains(&overlay.highlighted_edges, *u, *v) {
+            attributes.push(String::from("color=red, fontcolor=red"));
+        }
+
+        out += &format!(
+            "    {} {} {} [{}];\n",
+            u,
+            if directed { "->" } else { "--" },
+            v,
+            attributes.join(", ")
+        );
+    }
+
+    out + "}\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::{
+        directed_graph::DirectedGraph,
+        undirected_graph::{UndirectedGraph, Vertex, Weight},
+    };
+
+    #[test]
+    fn plain_graph() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(2, 1, 5);
+        g.add_edge(2, 3, -1);
+
+        let expected = "graph {
+    1;
+    2;
+    3;
+    1 -- 2 [label=\"5\"];
+    2 -- 3 [label=\"-1\"];
+}
+";
+        assert_eq!(expected, write_dot(&g, &Overlay::default()));
+    }
+
+    #[test]
+    fn overlay() {
+        let mut g = UndirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 5);
+        g.add_edge(2, 3, 4);
+        g.add_edge(4, 5, 3);
+
+        let overlay = Overlay {
+            bold_edges: HashSet::from([(2, 1)]),
+            highlighted_edges: HashSet::from([(2, 3)]),
+            vertex_groups: HashMap::from([(1, 0), (2, 0), (3, 0), (4, 1), (5, 1)]),
+        };
+
+        let expected = "graph {
+    1 [style=filled, fillcolor=lightblue];
+    2 [style=filled, fillcolor=lightblue];
+    3 [style=filled, fillcolor=lightblue];
+    4 [style=filled, fillcolor=lightcoral];
+    5 [style=filled, fillcolor=lightcoral];
+    1 -- 2 [label=\"5\", style=bold, penwidth=3];
+    2 -- 3 [label=\"4\", color=red, fontcolor=red];
+    4 -- 5 [label=\"3\"];
+}
+";
+        assert_eq!(expected, write_dot(&g, &overlay));
+    }
+
+    #[test]
+    fn directed_overlay_keeps_direction() {
+        let mut g = DirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(1, 2, 1);
+        g.add_edge(2, 1, 2);
+
+        let overlay = Overlay {
+            highlighted_edges: HashSet::from([(1, 2)]),
+            ..Overlay::default()
+        };
+
+        let expected = "digraph {
+    1;
+    2;
+    1 -> 2 [label=\"1\", color=red, fontcolor=red];
+    2 -> 1 [label=\"2\"];
+}
+";
+        assert_eq!(expected, write_dot(&g, &overlay));
+    }
+}
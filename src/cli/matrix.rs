@@ -0,0 +1,76 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::graph::graph::Graph;
+use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
+
+/// Reads a matrix written one row per line, with the entries separated by
+/// whitespace. Blank lines are skipped.
+pub fn read_matrix(path: &PathBuf) -> Vec<Vec<Weight>> {
+    parse_matrix(&fs::read_to_string(path).unwrap())
+}
+
+pub fn parse_matrix(input: &str) -> Vec<Vec<Weight>> {
+    let matrix: Vec<Vec<Weight>> = input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            l.split_whitespace()
+                .map(|w| {
+                    w.parse::<Weight>()
+                        .unwrap_or_else(|_| panic!("Invalid matrix entry {}", w))
+                })
+                .collect()
+        })
+        .collect();
+
+    if matrix.iter().any(|r| r.len() != matrix[0].len()) {
+        panic!("Every row of the matrix must have the same length");
+    }
+
+    matrix
+}
+
+/// The complete bipartite graph of an n × m matrix: row i is the vertex i + 1
+/// and column j the vertex n + j + 1.
+pub fn matrix_graph(matrix: &[Vec<Weight>]) -> UndirectedGraph<Vertex, Weight> {
+    let n = matrix.len();
+    let mut g = UndirectedGraph::new();
+
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, w) in row.iter().enumerate() {
+            g.add_edge(i + 1, n + j + 1, *w);
+        }
+    }
+
+    g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rectangular_matrix() {
+        let matrix = parse_matrix("4 1 3\n\n-2 0 5\n");
+
+        assert_eq!(vec![vec![4, 1, 3], vec![-2, 0, 5]], matrix);
+
+        let g = matrix_graph(&matrix);
+        assert_eq!(6, g.get_edges().len());
+        assert_eq!(Some(&-2), g.get_weight(&2, &3));
+        assert_eq!(Some(&3), g.get_weight(&1, &5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Every row of the matrix must have the same length")]
+    fn ragged_matrix() {
+        parse_matrix("1 2\n3\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid matrix entry x")]
+    fn invalid_entry() {
+        parse_matrix("1 x\n");
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use crate::graph::graph::Graph;
+use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
+
+// GEO distances are defined with this rounded value
+#[allow(clippy::approx_constant)]
+const PI: f64 = 3.141592;
+
+/// Reads a symmetric TSPLIB instance into a complete graph on the vertices
+/// 1..=DIMENSION, with distances rounded as the TSPLIB spec defines them.
+pub fn read_tsplib(path: &PathBuf) -> UndirectedGraph<Vertex, Weight> {
+    parse_tsplib(&fs::read_to_string(path).unwrap())
+}
+
+pub fn parse_tsplib(input: &str) -> UndirectedGraph<Vertex, Weight> {
+    let mut spec: HashMap<&str, &str> = HashMap::new();
+    let mut coords: Vec<(Vertex, f64, f64)> = vec![];
+    let mut weights: Vec<Weight> = vec![];
+    let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+
+    while let Some(line) = lines.next() {
+        let keyword = line
+            .split(|c: char| c == ':' || c.is_whitespace())
+            .next()
+            .unwrap();
+
+        match keyword {
+            "NODE_COORD_SECTION" => {
+                for _ in 0..dimension(&spec) {
+                    let mut line = lines
+                        .next()
+                        .unwrap_or_else(|| panic!("Missing node coordinates"))
+                        .split_whitespace();
+                    let v = line
+                        .next()
+                        .map(|v| v.parse::<Vertex>().unwrap())
+                        .unwrap_or_else(|| panic!("Invalid format"));
+                    let x = line
+                        .next()
+                        .map(|x| x.parse::<f64>().unwrap())
+                        .unwrap_or_else(|| panic!("Invalid format"));
+                    let y = line
+                        .next()
+                        .map(|y| y.parse::<f64>().unwrap())
+                        .unwrap_or_else(|| panic!("Invalid format"));
+                    coords.push((v, x, y));
+                }
+            }
+            "EDGE_WEIGHT_SECTION" => {
+                let count = matrix_entries(&spec).len();
+                while weights.len() < count {
+                    let line = lines
+                        .next()
+                        .unwrap_or_else(|| panic!("Missing edge weights"));
+                    weights.extend(
+                        line.split_whitespace()
+                            .map(|w| w.parse::<Weight>().unwrap()),
+                    );
+                }
+            }
+            "DISPLAY_DATA_SECTION" => {
+                for _ in 0..dimension(&spec) {
+                    lines.next();
+                }
+            }
+            "FIXED_EDGES_SECTION" => for _ in lines.by_ref().take_while(|l| *l != "-1") {},
+            "EOF" => break,
+            _ => {
+                let (key, value) = line
+                    .split_once(':')
+                    .unwrap_or_else(|| panic!("Invalid format"));
+                spec.insert(key.trim(), value.trim());
+            }
+        }
+    }
+
+    if let Some(t) = spec.get("TYPE").filter(|t| **t != "TSP") {
+        panic!("Unsupported TSPLIB type {}", t);
+    }
+
+    let mut g = UndirectedGraph::new();
+    match spec.get("EDGE_WEIGHT_TYPE").copied() {
+        Some("EXPLICIT") => {
+            for ((i, j), w) in matrix_entries(&spec).into_iter().zip(weights) {
+                if i != j {
+                    g.add_edge(i + 1, j + 1, w);
+                }
+            }
+        }
+        Some(distance) => {
+            for (i, (u, ux, uy)) in coords.iter().enumerate() {
+                for (v, vx, vy) in &coords[i + 1..] {
+                    g.add_edge(*u, *v, euclidean_distance(distance, (*ux, *uy), (*vx, *vy)));
+                }
+            }
+        }
+        None => panic!("Missing EDGE_WEIGHT_TYPE"),
+    }
+
+    g
+}
+
+fn dimension(spec: &HashMap<&str, &str>) -> usize {
+    spec.get("DIMENSION")
+        .map(|n| n.parse::<usize>().unwrap())
+        .unwrap_or_else(|| panic!("Missing DIMENSION"))
+}
+
+/// Matrix cells in the order the EDGE_WEIGHT_SECTION lists them. The weights
+/// are symmetric, so a column-wise triangle reads like the opposite
+/// row-wise one.
+fn matrix_entries(spec: &HashMap<&str, &str>) -> Vec<(usize, usize)> {
+    let n = dimension(spec);
+    let format = spec
+        .get("EDGE_WEIGHT_FORMAT")
+        .unwrap_or_else(|| panic!("Missing EDGE_WEIGHT_FORMAT"));
+
+    (0..n)
+        .flat_map(|i| {
+            let columns = match *format {
+                "FULL_MATRIX" => 0..n,
+                "UPPER_ROW" | "LOWER_COL" => i + 1..n,
+                "UPPER_DIAG_ROW" | "LOWER_DIAG_COL" => i..n,
+                "LOWER_ROW" | "UPPER_COL" => 0..i,
+                "LOWER_DIAG_ROW" | "UPPER_DIAG_COL" => 0..i + 1,
+                _ => panic!("Unsupported EDGE_WEIGHT_FORMAT {}", format),
+            };
+            columns.map(move |j| (i, j))
+        })
+        .collect()
+}
+
+fn euclidean_distance(distance: &str, (ux, uy): (f64, f64), (vx, vy): (f64, f64)) -> Weight {
+    let (dx, dy) = (ux - vx, uy - vy);
+
+    match distance {
+        "EUC_2D" => (dx.hypot(dy) + 0.5) as Weight,
+        "CEIL_2D" => dx.hypot(dy).ceil() as Weight,
+        "ATT" => {
+            let r = ((dx * dx + dy * dy) / 10.0).sqrt();
+            let t = (r + 0.5) as Weight;
+            if (t as f64) < r {
+                t + 1
+            } else {
+                t
+            }
+        }
+        "GEO" => {
+            // Coordinates are DDD.MM degrees and minutes, latitude first
+            let radians = |x: f64| {
+                let degrees = x.trunc();
+                PI * (degrees + 5.0 * (x - degrees) / 3.0) / 180.0
+            };
+            let (lat_u, lon_u, lat_v, lon_v) = (radians(ux), radians(uy), radians(vx), radians(vy));
+            let q1 = (lon_u - lon_v).cos();
+            let q2 = (lat_u - lat_v).cos();
+            let q3 = (lat_u + lat_v).cos();
+
+            (6378.388 * (0.5 * ((1.0 + q1) * q2 - (1.0 - q1) * q3)).acos() + 1.0) as Weight
+        }
+        _ => panic!("Unsupported EDGE_WEIGHT_TYPE {}", distance),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn euc_2d() {
+        let g = parse_tsplib(
+            "NAME : square
+TYPE : TSP
+DIMENSION : 3
+EDGE_WEIGHT_TYPE : EUC_2D
+NODE_COORD_SECTION
+1 0 0
+2 3 4
+3 0 1.5
+EOF
+",
+        );
+
+        assert_eq!(3, g.get_edges().len());
+        assert_eq!(Some(&5), g.get_weight(&1, &2));
+        assert_eq!(Some(&2), g.get_weight(&1, &3));
+        assert_eq!(Some(&4), g.get_weight(&2, &3));
+    }
+
+    #[test]
+    fn att() {
+        let g = parse_tsplib(
+            "TYPE: TSP
+DIMENSION: 2
+EDGE_WEIGHT_TYPE: ATT
+NODE_COORD_SECTION
+1 0 0
+2 30 40
+",
+        );
+
+        // sqrt(2500 / 10) = 15.81, rounded to 16 and kept as it is not below
+        assert_eq!(Some(&16), g.get_weight(&1, &2));
+    }
+
+    #[test]
+    fn geo() {
+        let g = parse_tsplib(
+            "DIMENSION: 2
+EDGE_WEIGHT_TYPE: GEO
+NODE_COORD_SECTION
+1 16.47 96.10
+2 16.47 94.44
+",
+        );
+
+        assert_eq!(Some(&153), g.get_weight(&1, &2));
+    }
+
+    #[test]
+    fn full_matrix() {
+        let g = parse_tsplib(
+            "DIMENSION: 3
+EDGE_WEIGHT_TYPE: EXPLICIT
+EDGE_WEIGHT_FORMAT: FULL_MATRIX
+EDGE_WEIGHT_SECTION
+0 1 2
+1 0 3
+2 3 0
+EOF
+",
+        );
+
+        assert_eq!(3, g.get_edges().len());
+        assert_eq!(Some(&1), g.get_weight(&1, &2));
+        assert_eq!(Some(&2), g.get_weight(&1, &3));
+        assert_eq!(Some(&3), g.get_weight(&2, &3));
+    }
+
+    #[test]
+    fn triangular_matrices() {
+        let formats = [
+            ("UPPER_ROW", "1 2 4\n3 5\n6"),
+            ("LOWER_ROW", "1\n2 3\n4 5 6"),
+            ("UPPER_DIAG_ROW", "0 1 2 4\n0 3 5\n0 6\n0"),
+            ("LOWER_DIAG_ROW", "0\n1 0\n2 3 0\n4 5 6 0"),
+            ("UPPER_COL", "1 2 3 4 5 6"),
+            ("LOWER_COL", "1 2 4 3 5 6"),
+            ("UPPER_DIAG_COL", "0 1 0 2 3 0 4 5 6 0"),
+            ("LOWER_DIAG_COL", "0 1 2 4 0 3 5 0 6 0"),
+        ];
+
+        for (format, section) in formats {
+            let g = parse_tsplib(&format!(
+                "DIMENSION: 4
+EDGE_WEIGHT_TYPE: EXPLICIT
+EDGE_WEIGHT_FORMAT: {}
+EDGE_WEIGHT_SECTION
+{}
+EOF
+",
+                format, section
+            ));
+
+            assert_eq!(6, g.get_edges().len(), "{}", format);
+            for (u, v, w) in [
+                (1, 2, 1),
+                (1, 3, 2),
+                (2, 3, 3),
+                (1, 4, 4),
+                (2, 4, 5),
+                (3, 4, 6),
+            ] {
+                assert_eq!(Some(&w), g.get_weight(&u, &v), "{}", format);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn asymmetric_instance() {
+        parse_tsplib(
+            "TYPE: ATSP
+DIMENSION: 2
+EDGE_WEIGHT_TYPE: EXPLICIT
+EDGE_WEIGHT_FORMAT: FULL_MATRIX
+EDGE_WEIGHT_SECTION
+0 1
+2 0
+",
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn missing_dimension() {
+        parse_tsplib(
+            "EDGE_WEIGHT_TYPE: EUC_2D
+NODE_COORD_SECTION
+1 0 0
+",
+        );
+    }
+}
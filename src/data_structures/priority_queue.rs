@@ -44,8 +44,10 @@ impl<T: Eq + Hash + Clone + PartialOrd + Display> PriorityQueue<T> {
             self.h[0] = max.clone();
             self.h.remove(self.h.len() - 1);
             self.indexes.remove(&min.0);
-            self.indexes.insert(max.0.clone(), 0);
-            self.min_heapify(0);
+            if !self.h.is_empty() {
+                self.indexes.insert(max.0.clone(), 0);
+                self.min_heapify(0);
+            }
 
             min
         })
@@ -118,3 +120,44 @@ fn left(i: usize) -> usize {
 fn right(i: usize) -> usize {
     2 * i + 2
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extract_min_in_priority_order() {
+        let mut pq = PriorityQueue::from(&vec![
+            PriorityQueueItem(1, 5),
+            PriorityQueueItem(2, -3),
+            PriorityQueueItem(3, 7),
+        ]);
+
+        assert_eq!(Some(PriorityQueueItem(2, -3)), pq.extract_min());
+        assert_eq!(Some(PriorityQueueItem(1, 5)), pq.extract_min());
+        assert_eq!(Some(PriorityQueueItem(3, 7)), pq.extract_min());
+        assert_eq!(None, pq.extract_min());
+    }
+
+    #[test]
+    fn extract_min_last_element_removes_index() {
+        let mut pq = PriorityQueue::new();
+
+        pq.insert(PriorityQueueItem(1, 5));
+        pq.extract_min();
+        pq.insert(PriorityQueueItem(2, 3));
+
+        assert_eq!(None, pq.get_index(&1));
+        assert_eq!(Some(0), pq.get_index(&2));
+    }
+
+    #[test]
+    fn decrease_key_moves_element_up() {
+        let mut pq = PriorityQueue::from(&vec![PriorityQueueItem(1, 5), PriorityQueueItem(2, 3)]);
+
+        let i = pq.get_index(&1).unwrap();
+        pq.decrease_key(i, 1);
+
+        assert_eq!(Some(PriorityQueueItem(1, 1)), pq.extract_min());
+    }
+}
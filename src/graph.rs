@@ -1,2 +1,4 @@
+pub mod directed_graph;
+pub mod generators;
 pub mod graph;
 pub mod undirected_graph;
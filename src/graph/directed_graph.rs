@@ -0,0 +1,218 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::graph::graph::{Edge, Graph};
+
+#[derive(PartialEq, Eq)]
+pub struct DirectedGraph<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash> {
+    adj_matrix: HashMap<V, HashMap<V, W>>,
+    in_adj_matrix: HashMap<V, HashMap<V, W>>,
+    vertices: HashSet<V>,
+    edges: HashSet<(V, V, W)>,
+}
+
+impl<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash> DirectedGraph<V, W> {
+    pub fn new() -> DirectedGraph<V, W> {
+        DirectedGraph {
+            adj_matrix: HashMap::new(),
+            in_adj_matrix: HashMap::new(),
+            vertices: HashSet::new(),
+            edges: HashSet::new(),
+        }
+    }
+}
+
+impl<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash> Default for DirectedGraph<V, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash> Graph<V, W> for DirectedGraph<V, W> {
+    fn add_edge(&mut self, u: V, v: V, w: W) {
+        if let Some(old) = self.get_weight(&u, &v).cloned() {
+            self.edges.remove(&(u, v, old));
+        }
+
+        for t in [u, v] {
+            self.adj_matrix.entry(t).or_default();
+            self.in_adj_matrix.entry(t).or_default();
+            self.vertices.insert(t);
+        }
+        self.adj_matrix.get_mut(&u).unwrap().insert(v, w.clone());
+        self.in_adj_matrix.get_mut(&v).unwrap().insert(u, w.clone());
+
+        self.edges.insert((u, v, w));
+    }
+
+    fn _get_size(&self) -> usize {
+        self.vertices.len()
+    }
+
+    fn _get_adj_list(&self, v: &V) -> Option<&HashMap<V, W>> {
+        self.adj_matrix.get(v)
+    }
+
+    fn get_weight(&self, u: &V, v: &V) -> Option<&W> {
+        self.adj_matrix.get(u).and_then(|el| el.get(v))
+    }
+
+    fn get_vertices(&self) -> &HashSet<V> {
+        &self.vertices
+    }
+
+    fn get_edges(&self) -> &HashSet<Edge<V, W>> {
+        &self.edges
+    }
+
+    fn delete_edge(&mut self, u: &V, v: &V) {
+        if let Some(w) = self.get_weight(u, v).cloned() {
+            self.adj_matrix.get_mut(u).unwrap().remove(v);
+            self.in_adj_matrix.get_mut(v).unwrap().remove(u);
+
+            clean_vertex(self, u);
+            clean_vertex(self, v);
+
+            self.edges.remove(&(*u, *v, w));
+        }
+    }
+
+    fn is_directed(&self) -> bool {
+        true
+    }
+}
+
+fn clean_vertex<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash>(
+    g: &mut DirectedGraph<V, W>,
+    t: &V,
+) {
+    if g.adj_matrix.get(t).is_some_and(|adj| adj.is_empty())
+        && g.in_adj_matrix.get(t).is_some_and(|adj| adj.is_empty())
+    {
+        g.adj_matrix.remove(t);
+        g.in_adj_matrix.remove(t);
+        g.vertices.remove(t);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::graph::graph::{Edge, Graph};
+    use crate::graph::undirected_graph::{Vertex, Weight};
+
+    use super::DirectedGraph;
+
+    #[test]
+    fn add_edge() {
+        let mut g = DirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 4, 3);
+
+        let expected_edges: HashSet<Edge<Vertex, Weight>> = HashSet::from([(0, 1, 2), (1, 4, 3)]);
+        assert_eq!(g.edges, expected_edges);
+
+        let expected_vertices: HashSet<Vertex> = HashSet::from([0, 1, 4]);
+        assert_eq!(g.vertices, expected_vertices);
+
+        let expected_adj_matrix: HashMap<Vertex, HashMap<Vertex, Weight>> = HashMap::from([
+            (0, HashMap::from([(1, 2)])),
+            (1, HashMap::from([(4, 3)])),
+            (4, HashMap::new()),
+        ]);
+        assert_eq!(g.adj_matrix, expected_adj_matrix);
+
+        let expected_in_adj_matrix: HashMap<Vertex, HashMap<Vertex, Weight>> = HashMap::from([
+            (0, HashMap::new()),
+            (1, HashMap::from([(0, 2)])),
+            (4, HashMap::from([(1, 3)])),
+        ]);
+        assert_eq!(g.in_adj_matrix, expected_in_adj_matrix);
+    }
+
+    #[test]
+    fn add_edge_replaces_weight() {
+        let mut g = DirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(0, 1, 2);
+        g.add_edge(0, 1, 5);
+
+        let expected_edges: HashSet<Edge<Vertex, Weight>> = HashSet::from([(0, 1, 5)]);
+        assert_eq!(g.edges, expected_edges);
+        assert_eq!(Some(&5), g.get_weight(&0, &1));
+    }
+
+    #[test]
+    fn get_adj_list_existing_vertex() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 4, 3);
+        g.add_edge(4, 1, 7);
+
+        let adj_list = HashMap::from([(4, 3)]);
+        let expected = Some(&adj_list);
+        let current = g._get_adj_list(&1);
+
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn get_adj_list_sink_vertex() {
+        let mut g = DirectedGraph::<Vertex, Weight>::new();
+
+        g.add_edge(0, 1, 2);
+
+        let adj_list = HashMap::new();
+        let expected = Some(&adj_list);
+        let current = g._get_adj_list(&1);
+
+        assert_eq!(expected, current);
+    }
+
+    #[test]
+    fn get_weight_is_directed() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(0, 1, 2);
+
+        assert_eq!(Some(&2), g.get_weight(&0, &1));
+        assert_eq!(None, g.get_weight(&1, &0));
+    }
+
+    #[test]
+    fn delete_edge_existing_edge() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 4, 3);
+
+        g.delete_edge(&0, &1);
+
+        let expected_edges: HashSet<(Vertex, Vertex, Weight)> = HashSet::from([(1, 4, 3)]);
+        assert_eq!(g.edges, expected_edges);
+
+        let expected_vertices: HashSet<Vertex> = HashSet::from([1, 4]);
+        assert_eq!(g.vertices, expected_vertices);
+
+        let expected_adj_matrix: HashMap<Vertex, HashMap<Vertex, Weight>> =
+            HashMap::from([(1, HashMap::from([(4, 3)])), (4, HashMap::new())]);
+        assert_eq!(g.adj_matrix, expected_adj_matrix);
+    }
+
+    #[test]
+    fn delete_edge_keeps_vertex_with_incoming_edges() {
+        let mut g = DirectedGraph::new();
+
+        g.add_edge(0, 1, 2);
+        g.add_edge(1, 4, 3);
+
+        g.delete_edge(&1, &4);
+
+        let expected_vertices: HashSet<Vertex> = HashSet::from([0, 1]);
+        assert_eq!(g.vertices, expected_vertices);
+        assert_eq!(None, g.get_weight(&1, &4));
+    }
+}
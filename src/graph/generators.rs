@@ -0,0 +1,5 @@
+pub mod families;
+pub mod random;
+pub mod rng;
+#[cfg(test)]
+pub mod testing;
@@ -0,0 +1,510 @@
+use std::collections::HashSet;
+use std::f64::consts::PI;
+use std::ops::RangeInclusive;
+
+use crate::graph::generators::rng::Rng;
+use crate::graph::graph::Graph;
+use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
+
+/// How the edges of a generated graph are weighted. Every family places its
+/// vertices in the plane (or, for the hypercube, at the corners of the unit
+/// cube), and Euclidean weights are the distances between them multiplied by
+/// `scale` and rounded.
+#[derive(Debug, Clone)]
+pub enum WeightPolicy {
+    Unit,
+    Random {
+        seed: u64,
+        range: RangeInclusive<Weight>,
+    },
+    Euclidean {
+        scale: f64,
+    },
+}
+
+struct Builder<'a> {
+    g: UndirectedGraph<Vertex, Weight>,
+    policy: &'a WeightPolicy,
+    rng: Rng,
+    positions: Vec<Vec<f64>>,
+}
+
+impl<'a> Builder<'a> {
+    /// `positions[v - 1]` is where the vertex v lies.
+    fn new(policy: &'a WeightPolicy, positions: Vec<Vec<f64>>) -> Builder<'a> {
+        let seed = match policy {
+            WeightPolicy::Random { seed, .. } => *seed,
+            _ => 0,
+        };
+
+        Builder {
+            g: UndirectedGraph::new(),
+            policy,
+            rng: Rng::new(seed),
+            positions,
+        }
+    }
+
+    fn edge(&mut self, u: Vertex, v: Vertex) {
+        let w = match self.policy {
+            WeightPolicy::Unit => 1,
+            WeightPolicy::Random { range, .. } => self.rng.in_range(range),
+            WeightPolicy::Euclidean { scale } => {
+                let distance = self.positions[u - 1]
+                    .iter()
+                    .zip(&self.positions[v - 1])
+                    .map(|(a, b)| (a - b) * (a - b))
+                    .sum::<f64>()
+                    .sqrt();
+                (distance * scale).round() as Weight
+            }
+        };
+
+        self.g.add_edge(u, v, w);
+    }
+}
+
+fn circle(n: usize, radius: f64) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| {
+            let angle = 2.0 * PI * i as f64 / n as f64;
+            vec![radius * angle.cos(), radius * angle.sin()]
+        })
+        .collect()
+}
+
+/// The path 1 - 2 - ... - n, laid out on a line.
+pub fn path(n: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    let mut b = Builder::new(policy, (0..n).map(|i| vec![i as f64, 0.0]).collect());
+
+    for v in 2..=n {
+        b.edge(v - 1, v);
+    }
+
+    b.g
+}
+
+/// The cycle on 1..=n, laid out on the unit circle.
+pub fn cycle(n: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    if n < 3 {
+        panic!("A cycle needs at least 3 vertices");
+    }
+    let mut b = Builder::new(policy, circle(n, 1.0));
+
+    for v in 2..=n {
+        b.edge(v - 1, v);
+    }
+    b.edge(n, 1);
+
+    b.g
+}
+
+/// The star with center 1 and leaves 2..=n on the unit circle around it.
+pub fn star(n: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    let mut positions = vec![vec![0.0, 0.0]];
+    positions.extend(circle(n.saturating_sub(1), 1.0));
+    let mut b = Builder::new(policy, positions);
+
+    for v in 2..=n {
+        b.edge(1, v);
+    }
+
+    b.g
+}
+
+/// K_n on 1..=n, laid out on the unit circle.
+pub fn complete(n: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    let mut b = Builder::new(policy, circle(n, 1.0));
+
+    for u in 1..=n {
+        for v in u + 1..=n {
+            b.edge(u, v);
+        }
+    }
+
+    b.g
+}
+
+/// K_{m,n} with the sides 1..=m and m+1..=m+n on two parallel lines.
+pub fn complete_bipartite(
+    m: usize,
+    n: usize,
+    policy: &WeightPolicy,
+) -> UndirectedGraph<Vertex, Weight> {
+    let positions = (0..m)
+        .map(|i| vec![0.0, i as f64])
+        .chain((0..n).map(|j| vec![1.0, j as f64]))
+        .collect();
+    let mut b = Builder::new(policy, positions);
+
+    for u in 1..=m {
+        for v in m + 1..=m + n {
+            b.edge(u, v);
+        }
+    }
+
+    b.g
+}
+
+fn grid_positions(rows: usize, cols: usize) -> Vec<Vec<f64>> {
+    (0..rows * cols)
+        .map(|i| vec![(i % cols) as f64, (i / cols) as f64])
+        .collect()
+}
+
+/// The rows x cols grid; the cell in row r and column c (from 0) is the
+/// vertex r * cols + c + 1.
+pub fn grid(rows: usize, cols: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    let mut b = Builder::new(policy, grid_positions(rows, cols));
+
+    for r in 0..rows {
+        for c in 0..cols {
+            let v = r * cols + c + 1;
+            if c + 1 < cols {
+                b.edge(v, v + 1);
+            }
+            if r + 1 < rows {
+                b.edge(v, v + cols);
+            }
+        }
+    }
+
+    b.g
+}
+
+/// The grid with wrap-around edges, numbered as `grid`. Both sides need at
+/// least 3 cells, otherwise wrapping would add parallel edges. Wrap-around
+/// edges span the whole grid under Euclidean weights.
+pub fn torus(rows: usize, cols: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    if rows < 3 || cols < 3 {
+        panic!("A torus needs at least 3 rows and 3 columns");
+    }
+    let mut b = Builder::new(policy, grid_positions(rows, cols));
+
+    for r in 0..rows {
+        for c in 0..cols {
+            let v = r * cols + c + 1;
+            b.edge(v, r * cols + (c + 1) % cols + 1);
+            b.edge(v, (r + 1) % rows * cols + c + 1);
+        }
+    }
+
+    b.g
+}
+
+/// The d-dimensional hypercube: the vertex x + 1 is the corner whose
+/// coordinates are the bits of x, adjacent to the corners one bit away.
+pub fn hypercube(d: usize, policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    let n = 1usize << d;
+    let positions = (0..n)
+        .map(|x| (0..d).map(|i| (x >> i & 1) as f64).collect())
+        .collect();
+    let mut b = Builder::new(policy, positions);
+
+    for x in 0..n {
+        for i in 0..d {
+            let y = x ^ (1 << i);
+            if x < y {
+                b.edge(x + 1, y + 1);
+            }
+        }
+    }
+
+    b.g
+}
+
+/// The Petersen graph: the outer cycle 1..=5, the inner pentagram 6..=10 and
+/// the spokes i - i+5.
+pub fn petersen(policy: &WeightPolicy) -> UndirectedGraph<Vertex, Weight> {
+    let mut positions = circle(5, 2.0);
+    positions.extend(circle(5, 1.0));
+    let mut b = Builder::new(policy, positions);
+
+    for i in 0..5 {
+        b.edge(i + 1, (i + 1) % 5 + 1);
+        b.edge(i + 6, (i + 2) % 5 + 6);
+        b.edge(i + 1, i + 6);
+    }
+
+    b.g
+}
+
+/// Barabási–Albert preferential attachment: starting from K_{m+1}, every new
+/// vertex links to m distinct vertices picked with probability proportional
+/// to their degree. Vertices are laid out on the unit circle.
+pub fn barabasi_albert(
+    n: usize,
+    m: usize,
+    seed: u64,
+    policy: &WeightPolicy,
+) -> UndirectedGraph<Vertex, Weight> {
+    if m == 0 || m >= n {
+        panic!("Barabási–Albert needs 0 < m < n");
+    }
+    let mut rng = Rng::new(seed);
+    let mut b = Builder::new(policy, circle(n, 1.0));
+    // Every vertex appears once per incident edge
+    let mut endpoints: Vec<Vertex> = vec![];
+
+    for u in 1..=m + 1 {
+        for v in u + 1..=m + 1 {
+            b.edge(u, v);
+            endpoints.extend([u, v]);
+        }
+    }
+    for v in m + 2..=n {
+        let mut targets: Vec<Vertex> = vec![];
+        while targets.len() < m {
+            let u = endpoints[rng.below(endpoints.len())];
+            if !targets.contains(&u) {
+                targets.push(u);
+            }
+        }
+        for u in targets {
+            b.edge(u, v);
+            endpoints.extend([u, v]);
+        }
+    }
+
+    b.g
+}
+
+/// Watts–Strogatz small world: the ring where every vertex is adjacent to
+/// its k nearest neighbours (k even), then each edge keeps its first
+/// endpoint and has the other one moved to a random vertex with probability
+/// beta. Vertices are laid out on the unit circle.
+pub fn watts_strogatz(
+    n: usize,
+    k: usize,
+    beta: f64,
+    seed: u64,
+    policy: &WeightPolicy,
+) -> UndirectedGraph<Vertex, Weight> {
+    if k % 2 == 1 || k >= n {
+        panic!("Watts–Strogatz needs an even k smaller than n");
+    }
+    let mut rng = Rng::new(seed);
+    let mut edges: Vec<(Vertex, Vertex)> = vec![];
+    let mut present: HashSet<(Vertex, Vertex)> = HashSet::new();
+    let key = |u: Vertex, v: Vertex| (u.min(v), u.max(v));
+
+    for j in 1..=k / 2 {
+        for u in 1..=n {
+            let v = (u - 1 + j) % n + 1;
+            edges.push((u, v));
+            present.insert(key(u, v));
+        }
+    }
+    let mut degree = vec![k; n + 1];
+    for (u, v) in edges.iter_mut() {
+        // A vertex adjacent to every other one has nowhere to go
+        if rng.next_f64() >= beta || degree[*u] + 1 >= n {
+            continue;
+        }
+        let w = loop {
+            let w = rng.below(n) + 1;
+            if w != *u && !present.contains(&key(*u, w)) {
+                break w;
+            }
+        };
+        present.remove(&key(*u, *v));
+        present.insert(key(*u, w));
+        degree[*v] -= 1;
+        degree[w] += 1;
+        *v = w;
+    }
+
+    let mut b = Builder::new(policy, circle(n, 1.0));
+    for (u, v) in edges {
+        b.edge(u, v);
+    }
+
+    b.g
+}
+
+/// Random geometric graph: n points drawn uniformly in the unit square,
+/// adjacent when at most `radius` apart.
+pub fn random_geometric(
+    n: usize,
+    radius: f64,
+    seed: u64,
+    policy: &WeightPolicy,
+) -> UndirectedGraph<Vertex, Weight> {
+    let mut rng = Rng::new(seed);
+    let positions: Vec<Vec<f64>> = (0..n)
+        .map(|_| vec![rng.next_f64(), rng.next_f64()])
+        .collect();
+    let mut b = Builder::new(policy, positions.clone());
+
+    for u in 0..n {
+        for v in u + 1..n {
+            let (dx, dy) = (
+                positions[u][0] - positions[v][0],
+                positions[u][1] - positions[v][1],
+            );
+            if dx.hypot(dy) <= radius {
+                b.edge(u + 1, v + 1);
+            }
+        }
+    }
+
+    b.g
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::bipartite::is_bipartite::{is_bipartite, Bipartiteness};
+    use crate::algorithms::coloring::exact::exact;
+    use crate::algorithms::connected_components::count_connected_components::count_connected_components;
+    use crate::algorithms::cycles::is_acyclic::is_acyclic;
+
+    fn degrees(g: &UndirectedGraph<Vertex, Weight>) -> Vec<usize> {
+        let mut degrees: Vec<usize> = g
+            .get_vertices()
+            .iter()
+            .map(|v| g._get_adj_list(v).unwrap().len())
+            .collect();
+        degrees.sort();
+        degrees
+    }
+
+    fn bipartite(g: &UndirectedGraph<Vertex, Weight>) -> bool {
+        matches!(is_bipartite(g), Bipartiteness::Bipartite(_, _))
+    }
+
+    #[test]
+    fn trees() {
+        let p = path(6, &WeightPolicy::Unit);
+        let s = star(6, &WeightPolicy::Unit);
+
+        for g in [&p, &s] {
+            assert_eq!(5, g.get_edges().len());
+            assert!(is_acyclic(g));
+            assert_eq!(1, count_connected_components(g));
+        }
+        assert_eq!(vec![1, 1, 2, 2, 2, 2], degrees(&p));
+        assert_eq!(vec![1, 1, 1, 1, 1, 5], degrees(&s));
+    }
+
+    #[test]
+    fn cycles() {
+        assert!(bipartite(&cycle(6, &WeightPolicy::Unit)));
+        assert!(!bipartite(&cycle(7, &WeightPolicy::Unit)));
+        assert_eq!(vec![2; 7], degrees(&cycle(7, &WeightPolicy::Unit)));
+    }
+
+    #[test]
+    fn complete_graphs() {
+        let k5 = complete(5, &WeightPolicy::Unit);
+        let k34 = complete_bipartite(3, 4, &WeightPolicy::Unit);
+
+        assert_eq!(10, k5.get_edges().len());
+        assert_eq!(5, exact(&k5).num_colors);
+        assert_eq!(12, k34.get_edges().len());
+        assert_eq!(vec![3, 3, 3, 3, 4, 4, 4], degrees(&k34));
+        assert!(bipartite(&k34));
+    }
+
+    #[test]
+    fn grids() {
+        let g = grid(3, 4, &WeightPolicy::Unit);
+        let t = torus(3, 4, &WeightPolicy::Unit);
+
+        assert_eq!(17, g.get_edges().len());
+        assert!(bipartite(&g));
+        assert_eq!(Some(&1), g.get_weight(&6, &10));
+        assert_eq!(24, t.get_edges().len());
+        assert_eq!(vec![4; 12], degrees(&t));
+        // Three rows wrap around into an odd cycle
+        assert!(!bipartite(&t));
+    }
+
+    #[test]
+    fn hypercubes() {
+        let g = hypercube(4, &WeightPolicy::Unit);
+
+        assert_eq!(32, g.get_edges().len());
+        assert_eq!(vec![4; 16], degrees(&g));
+        assert!(bipartite(&g));
+        assert_eq!(Some(&1), g.get_weight(&1, &9));
+    }
+
+    #[test]
+    fn petersen_graph() {
+        let g = petersen(&WeightPolicy::Unit);
+
+        assert_eq!(15, g.get_edges().len());
+        assert_eq!(vec![3; 10], degrees(&g));
+        assert_eq!(3, exact(&g).num_colors);
+        assert!(!bipartite(&g));
+    }
+
+    #[test]
+    fn barabasi_albert_graph() {
+        let g = barabasi_albert(50, 3, 7, &WeightPolicy::Unit);
+
+        assert_eq!(6 + 46 * 3, g.get_edges().len());
+        assert_eq!(50, g.get_vertices().len());
+        assert_eq!(1, count_connected_components(&g));
+        assert!(degrees(&g)[0] >= 3);
+    }
+
+    #[test]
+    fn watts_strogatz_graph() {
+        let ring = watts_strogatz(20, 4, 0.0, 1, &WeightPolicy::Unit);
+        let rewired = watts_strogatz(20, 4, 0.5, 1, &WeightPolicy::Unit);
+        let random = watts_strogatz(6, 4, 1.0, 3, &WeightPolicy::Unit);
+
+        assert_eq!(vec![4; 20], degrees(&ring));
+        assert_eq!(Some(&1), ring.get_weight(&20, &2));
+        assert_eq!(40, rewired.get_edges().len());
+        assert_ne!(ring.get_edges(), rewired.get_edges());
+        assert_eq!(12, random.get_edges().len());
+    }
+
+    #[test]
+    fn random_geometric_graph() {
+        let none = random_geometric(30, 0.0, 2, &WeightPolicy::Unit);
+        let all = random_geometric(30, 1.5, 2, &WeightPolicy::Unit);
+        let g = random_geometric(30, 0.3, 2, &WeightPolicy::Euclidean { scale: 100.0 });
+
+        assert!(none.get_edges().is_empty());
+        assert_eq!(435, all.get_edges().len());
+        assert!(g.get_edges().iter().all(|(_, _, w)| (0..=30).contains(w)));
+    }
+
+    #[test]
+    fn euclidean_weights() {
+        let policy = WeightPolicy::Euclidean { scale: 10.0 };
+
+        assert!(grid(2, 2, &policy).get_edges().iter().all(|e| e.2 == 10));
+        assert!(hypercube(3, &policy).get_edges().iter().all(|e| e.2 == 10));
+        // The side of a square inscribed in the unit circle
+        assert!(cycle(4, &policy).get_edges().iter().all(|e| e.2 == 14));
+        assert_eq!(
+            Some(&10),
+            complete_bipartite(1, 1, &policy).get_weight(&1, &2)
+        );
+    }
+
+    #[test]
+    fn random_weights() {
+        let policy = WeightPolicy::Random {
+            seed: 9,
+            range: -3..=3,
+        };
+        let a = complete(8, &policy);
+        let b = complete(8, &policy);
+
+        assert_eq!(a.get_edges(), b.get_edges());
+        assert!(a.get_edges().iter().all(|e| (-3..=3).contains(&e.2)));
+        assert!(a.get_edges().iter().any(|e| e.2 < 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn torus_too_small() {
+        torus(2, 5, &WeightPolicy::Unit);
+    }
+}
@@ -0,0 +1,247 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::graph::generators::rng::Rng;
+use crate::graph::graph::Graph;
+use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
+
+/// Erdős–Rényi G(n, p) on the vertices 1..=n: every edge is present with
+/// probability p. Non-edges are skipped geometrically (Batagelj and Brandes),
+/// so the running time is linear in the number of edges.
+pub fn gnp(
+    n: usize,
+    p: f64,
+    weights: &RangeInclusive<Weight>,
+    rng: &mut Rng,
+) -> UndirectedGraph<Vertex, Weight> {
+    if !(0.0..=1.0).contains(&p) {
+        panic!("Probability must be in [0, 1]");
+    }
+    let mut g = UndirectedGraph::new();
+
+    if p == 0.0 {
+        return g;
+    }
+    if p == 1.0 {
+        for u in 1..=n {
+            for v in u + 1..=n {
+                g.add_edge(u, v, rng.in_range(weights));
+            }
+        }
+        return g;
+    }
+
+    let log_q = (1.0 - p).ln();
+    let (mut v, mut w) = (1usize, -1i64);
+    while v < n {
+        let skip = ((1.0 - rng.next_f64()).ln() / log_q).floor() as i64;
+        w += 1 + skip;
+        while w >= v as i64 && v < n {
+            w -= v as i64;
+            v += 1;
+        }
+        if v < n {
+            g.add_edge(w as usize + 1, v + 1, rng.in_range(weights));
+        }
+    }
+
+    g
+}
+
+/// Erdős–Rényi G(n, m) on the vertices 1..=n: m distinct edges drawn
+/// uniformly.
+pub fn gnm(
+    n: usize,
+    m: usize,
+    weights: &RangeInclusive<Weight>,
+    rng: &mut Rng,
+) -> UndirectedGraph<Vertex, Weight> {
+    let mut g = UndirectedGraph::new();
+
+    add_random_edges(&mut g, n, m, weights, rng);
+
+    g
+}
+
+/// Connected graph on the vertices 1..=n with m edges: a uniform random
+/// spanning tree of K_n (Aldous–Broder walk) plus m - n + 1 uniform extra
+/// edges.
+pub fn random_connected(
+    n: usize,
+    m: usize,
+    weights: &RangeInclusive<Weight>,
+    rng: &mut Rng,
+) -> UndirectedGraph<Vertex, Weight> {
+    if m + 1 < n {
+        panic!("A connected graph on {} vertices needs {} edges", n, n - 1);
+    }
+    let mut g = UndirectedGraph::new();
+
+    if n > 1 {
+        let mut visited = vec![false; n + 1];
+        let mut current = rng.below(n) + 1;
+        let mut remaining = n - 1;
+        visited[current] = true;
+
+        while remaining > 0 {
+            // Uniform among the other n - 1 vertices
+            let mut next = rng.below(n - 1) + 1;
+            if next >= current {
+                next += 1;
+            }
+            if !visited[next] {
+                visited[next] = true;
+                remaining -= 1;
+                g.add_edge(current, next, rng.in_range(weights));
+            }
+            current = next;
+        }
+    }
+
+    add_random_edges(&mut g, n, m + 1 - n.max(1), weights, rng);
+
+    g
+}
+
+/// Adds `count` uniform edges of K_n missing from `g`. When more than half of
+/// the missing edges are wanted, the ones to leave out are drawn instead.
+fn add_random_edges(
+    g: &mut UndirectedGraph<Vertex, Weight>,
+    n: usize,
+    count: usize,
+    weights: &RangeInclusive<Weight>,
+    rng: &mut Rng,
+) {
+    let existing: HashSet<(Vertex, Vertex)> =
+        g.get_edges().iter().map(|(u, v, _)| (*u, *v)).collect();
+    let missing = n * n.saturating_sub(1) / 2 - existing.len();
+
+    if count > missing {
+        panic!("Too many edges for {} vertices", n);
+    }
+
+    let mut draw = |excluded: &HashSet<(Vertex, Vertex)>| loop {
+        let u = rng.below(n) + 1;
+        let v = rng.below(n) + 1;
+        let edge = (u.min(v), u.max(v));
+        if u != v && !excluded.contains(&edge) {
+            return edge;
+        }
+    };
+
+    let mut chosen = existing;
+    if count <= missing / 2 {
+        // Kept in drawing order so that the weights follow from the seed
+        let mut added = vec![];
+        for _ in 0..count {
+            let edge = draw(&chosen);
+            chosen.insert(edge);
+            added.push(edge);
+        }
+        for (u, v) in added {
+            g.add_edge(u, v, rng.in_range(weights));
+        }
+    } else {
+        for _ in 0..missing - count {
+            let edge = draw(&chosen);
+            chosen.insert(edge);
+        }
+        let left_out = chosen;
+        for u in 1..=n {
+            for v in u + 1..=n {
+                if !left_out.contains(&(u, v)) {
+                    g.add_edge(u, v, rng.in_range(weights));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::connected_components::count_connected_components::count_connected_components;
+
+    fn check_edges(
+        g: &UndirectedGraph<Vertex, Weight>,
+        n: usize,
+        weights: &RangeInclusive<Weight>,
+    ) {
+        for (u, v, w) in g.get_edges() {
+            assert!(u < v);
+            assert!(*v <= n);
+            assert!(*u >= 1);
+            assert!(weights.contains(w));
+        }
+    }
+
+    #[test]
+    fn gnp_extremes() {
+        let mut rng = Rng::new(1);
+
+        assert_eq!(0, gnp(10, 0.0, &(1..=1), &mut rng).get_edges().len());
+        assert_eq!(45, gnp(10, 1.0, &(1..=1), &mut rng).get_edges().len());
+    }
+
+    #[test]
+    fn gnp_edge_count_close_to_expected() {
+        let mut rng = Rng::new(3);
+        let weights = -5..=5;
+        let g = gnp(200, 0.1, &weights, &mut rng);
+
+        // Expected 1990 edges, standard deviation about 42
+        let m = g.get_edges().len();
+        assert!((1800..2200).contains(&m), "{}", m);
+        check_edges(&g, 200, &weights);
+    }
+
+    #[test]
+    fn gnm_exact_edge_count() {
+        let weights = -10..=-1;
+
+        for (n, m) in [(10, 0), (10, 5), (10, 40), (10, 45), (50, 300)] {
+            let mut rng = Rng::new(n as u64 * 31 + m as u64);
+            let g = gnm(n, m, &weights, &mut rng);
+
+            assert_eq!(m, g.get_edges().len());
+            check_edges(&g, n, &weights);
+        }
+    }
+
+    #[test]
+    fn random_connected_is_connected() {
+        let weights = 0..=100;
+
+        for (n, m) in [(2, 1), (10, 9), (30, 40), (30, 400), (30, 435)] {
+            for seed in 0..10 {
+                let mut rng = Rng::new(seed);
+                let g = random_connected(n, m, &weights, &mut rng);
+
+                assert_eq!(m, g.get_edges().len());
+                assert_eq!(n, g.get_vertices().len());
+                assert_eq!(1, count_connected_components(&g));
+                check_edges(&g, n, &weights);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_graph() {
+        let a = random_connected(20, 40, &(-9..=9), &mut Rng::new(5));
+        let b = random_connected(20, 40, &(-9..=9), &mut Rng::new(5));
+
+        assert_eq!(a.get_edges(), b.get_edges());
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_edges() {
+        gnm(4, 7, &(1..=1), &mut Rng::new(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_few_edges_to_connect() {
+        random_connected(5, 3, &(1..=1), &mut Rng::new(0));
+    }
+}
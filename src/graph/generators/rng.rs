@@ -0,0 +1,84 @@
+use std::ops::RangeInclusive;
+
+/// SplitMix64 generator. The sequence for a seed is fixed, so generated
+/// graphs can be reproduced from the seed alone.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in [0, n).
+    pub fn below(&mut self, n: usize) -> usize {
+        if n == 0 {
+            panic!("Empty range");
+        }
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn in_range(&mut self, range: &RangeInclusive<i32>) -> i32 {
+        if range.is_empty() {
+            panic!("Empty range");
+        }
+        let span = (*range.end() as i64 - *range.start() as i64 + 1) as u64;
+        (*range.start() as i64 + (self.next_u64() % span) as i64) as i32
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn values_stay_in_range() {
+        let mut rng = Rng::new(7);
+
+        for _ in 0..1000 {
+            let x = rng.in_range(&(-3..=2));
+            assert!((-3..=2).contains(&x));
+            assert!(rng.below(5) < 5);
+            let f = rng.next_f64();
+            assert!((0.0..1.0).contains(&f));
+        }
+    }
+
+    #[test]
+    fn full_weight_range() {
+        let mut rng = Rng::new(1);
+
+        rng.in_range(&(i32::MIN..=i32::MAX));
+    }
+}
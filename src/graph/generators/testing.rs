@@ -0,0 +1,76 @@
+use std::ops::RangeInclusive;
+
+use crate::graph::generators::rng::Rng;
+use crate::graph::graph::Graph;
+use crate::graph::undirected_graph::{UndirectedGraph, Vertex, Weight};
+
+/// Adds up to `attempts` edges between uniform vertices of 0..n, skipping
+/// loops and pairs that are already joined.
+pub fn random_edges(
+    g: &mut dyn Graph<Vertex, Weight>,
+    n: usize,
+    attempts: usize,
+    weights: &RangeInclusive<Weight>,
+    rng: &mut Rng,
+) {
+    for _ in 0..attempts {
+        let (u, v) = (rng.below(n), rng.below(n));
+        if u != v && g.get_weight(&u, &v).is_none() {
+            g.add_edge(u, v, rng.in_range(weights));
+        }
+    }
+}
+
+/// `n` points with both coordinates in 0..50.
+pub fn random_points(n: usize, rng: &mut Rng) -> Vec<(i32, i32)> {
+    (0..n)
+        .map(|_| (rng.in_range(&(0..=49)), rng.in_range(&(0..=49))))
+        .collect()
+}
+
+/// The complete graph on 0..points.len() weighted by Manhattan distance.
+pub fn manhattan(points: &[(i32, i32)]) -> UndirectedGraph<Vertex, Weight> {
+    let mut g = UndirectedGraph::new();
+
+    for u in 0..points.len() {
+        for v in u + 1..points.len() {
+            let (a, b) = (points[u], points[v]);
+            g.add_edge(u, v, (a.0 - b.0).abs() + (a.1 - b.1).abs());
+        }
+    }
+
+    g
+}
+
+/// Cost of the cheapest Hamiltonian cycle of `g` on 0..n by trying every
+/// order of 1..n, or `None` if there is none.
+pub fn optimum(g: &dyn Graph<Vertex, Weight>, n: usize) -> Option<Weight> {
+    fn extend(
+        g: &dyn Graph<Vertex, Weight>,
+        tour: &mut Vec<Vertex>,
+        left: &mut Vec<Vertex>,
+        cost: Weight,
+    ) -> Option<Weight> {
+        let last = *tour.last().unwrap();
+        if left.is_empty() {
+            return g.get_weight(&last, &tour[0]).map(|w| cost + w);
+        }
+
+        let mut best = None;
+        for i in 0..left.len() {
+            let v = left.remove(i);
+            if let Some(w) = g.get_weight(&last, &v) {
+                tour.push(v);
+                if let Some(total) = extend(g, tour, left, cost + w) {
+                    best = Some(best.map_or(total, |b: Weight| b.min(total)));
+                }
+                tour.pop();
+            }
+            left.insert(i, v);
+        }
+
+        best
+    }
+
+    extend(g, &mut vec![0], &mut (1..n).collect(), 0)
+}
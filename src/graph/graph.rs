@@ -11,4 +11,5 @@ pub trait Graph<V, W> {
     fn get_vertices(&self) -> &HashSet<V>;
     fn get_edges(&self) -> &HashSet<Edge<V, W>>;
     fn delete_edge(&mut self, u: &V, v: &V);
+    fn is_directed(&self) -> bool;
 }
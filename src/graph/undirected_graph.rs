@@ -78,6 +78,10 @@ impl<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash> Graph<V, W>
             self.edges.remove(&e);
         });
     }
+
+    fn is_directed(&self) -> bool {
+        false
+    }
 }
 
 fn clean_vertex<V: Eq + Clone + Hash + Ord + Copy, W: Eq + Clone + Hash>(
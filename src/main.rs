@@ -3,25 +3,65 @@ pub mod cli;
 pub mod data_structures;
 pub mod graph;
 
+use algorithms::bipartite::is_bipartite::Bipartiteness;
+use algorithms::coloring::proper_coloring::Coloring;
 use algorithms::connected_components;
+use algorithms::cycles::simple_cycles::Cycles;
+use algorithms::euler::chinese_postman::PostmanRoute;
+use algorithms::euler::EulerError;
+use algorithms::flow::MaxFlow;
+use algorithms::matching::hopcroft_karp::BipartiteMatching;
+use algorithms::matching::hungarian::Assignment;
+use algorithms::shortest_paths::all_pairs::AllPairs;
+use algorithms::shortest_paths::astar::AStarResult;
+use algorithms::topological_sort::CycleError;
+use algorithms::tsp::tour::Tour;
 use clap::Parser;
 use cli::cli::Algorithm;
 use graph::{
     graph::Path,
     undirected_graph::{Vertex, Weight},
 };
+use std::collections::{HashMap, HashSet};
 
-use crate::cli::cli::{run_cli, Args};
+use crate::cli::cli::{run_cli, run_generate, Cli, Command};
 
 fn main() {
-    let args: Args = Args::parse();
+    let cli = Cli::parse();
 
+    let args = match (cli.command, cli.args) {
+        (Some(Command::Generate(generate)), _) => {
+            let out = run_generate(&generate);
+            if generate.output.is_none() {
+                print!("{}", out);
+            }
+            return;
+        }
+        (None, Some(args)) => args,
+        (None, None) => unreachable!(),
+    };
     let res = run_cli(&args);
 
     match args.algorithm {
         Algorithm::IsAcyclic => {
             println!("Is acylic result: {:}", res.downcast_ref::<bool>().unwrap());
         }
+        Algorithm::IsBipartite => match res.downcast_ref::<Bipartiteness>().unwrap() {
+            Bipartiteness::Bipartite(left, right) => {
+                let mut left: Vec<&Vertex> = left.iter().collect();
+                let mut right: Vec<&Vertex> = right.iter().collect();
+                left.sort();
+                right.sort();
+
+                println!("Is bipartite result: true");
+                println!("Left side: {:?}", left);
+                println!("Right side: {:?}", right);
+            }
+            Bipartiteness::OddCycle(cycle) => {
+                println!("Is bipartite result: false");
+                println!("Odd cycle: {:?}", cycle);
+            }
+        },
         Algorithm::KruskalNaive => {
             let path = res.downcast_ref::<Path<Vertex, Weight>>().unwrap();
             let weight: Weight = path.iter().map(|e| e.2).sum();
@@ -48,5 +88,165 @@ fn main() {
 
             println!("Connected components: {:}", connected_components);
         }
+        Algorithm::StConnected => {
+            let path = res.downcast_ref::<Option<Path<Vertex, Weight>>>().unwrap();
+
+            println!("Is s-t connected: {:}", path.is_some());
+            if let Some(path) = path {
+                println!("s-t path: {:?}", path);
+            }
+        }
+        Algorithm::AllCycles => {
+            let cycles = res.downcast::<Cycles>().unwrap();
+
+            for cycle in cycles {
+                println!("{:?}", cycle);
+            }
+        }
+        Algorithm::AStar => {
+            match res.downcast_ref::<Option<AStarResult>>().unwrap() {
+                Some(res) => {
+                    println!("A* path: {:?}", res.path);
+                    println!("A* cost: {:?}", res.cost);
+                    println!("A* expanded nodes: {:?}", res.expanded);
+                }
+                None => println!("A* path: target not reachable"),
+            };
+        }
+        Algorithm::Coloring => {
+            let coloring = res.downcast_ref::<Coloring>().unwrap();
+            let mut colors: Vec<(&Vertex, &usize)> = coloring.colors.iter().collect();
+            colors.sort();
+
+            println!("Coloring: {:?}", colors);
+            println!("Number of colors: {:}", coloring.num_colors);
+        }
+        Algorithm::VertexCover => {
+            let cover = res.downcast_ref::<HashSet<Vertex>>().unwrap();
+            let mut cover: Vec<&Vertex> = cover.iter().collect();
+            cover.sort();
+
+            println!("Vertex cover: {:?}", cover);
+            println!("Vertex cover size: {:}", cover.len());
+        }
+        Algorithm::MinimumVertexCover => {
+            let cover = res.downcast_ref::<HashSet<Vertex>>().unwrap();
+            let mut cover: Vec<&Vertex> = cover.iter().collect();
+            cover.sort();
+
+            println!("Minimum vertex cover: {:?}", cover);
+            println!("Minimum vertex cover size: {:}", cover.len());
+        }
+        Algorithm::Bridges => {
+            let bridges = res.downcast_ref::<Path<Vertex, Weight>>().unwrap();
+
+            println!("Bridges: {:?}", bridges);
+            println!("Number of bridges: {:}", bridges.len());
+        }
+        Algorithm::ArticulationPoints => {
+            let points = res.downcast_ref::<HashSet<Vertex>>().unwrap();
+            let mut points: Vec<&Vertex> = points.iter().collect();
+            points.sort();
+
+            println!("Articulation points: {:?}", points);
+            println!("Number of articulation points: {:}", points.len());
+        }
+        Algorithm::StronglyConnectedComponents | Algorithm::WeaklyConnectedComponents => {
+            let labels = res.downcast_ref::<HashMap<Vertex, usize>>().unwrap();
+            let mut components: Vec<Vec<Vertex>> =
+                vec![vec![]; labels.values().max().map_or(0, |l| l + 1)];
+            for (v, label) in labels {
+                components[*label].push(*v);
+            }
+            for component in components.iter_mut() {
+                component.sort();
+            }
+
+            println!("Components: {:?}", components);
+            println!("Number of components: {:}", components.len());
+        }
+        Algorithm::EdmondsKarp | Algorithm::Dinic | Algorithm::PushRelabel => {
+            let res = res.downcast_ref::<MaxFlow>().unwrap();
+            let mut source_side: Vec<&Vertex> = res.source_side.iter().collect();
+            source_side.sort();
+
+            println!("Max flow: {:}", res.value);
+            println!("Flow: {:?}", res.flow);
+            println!("Source side of the min cut: {:?}", source_side);
+            println!("Min cut edges: {:?}", res.cut);
+        }
+        Algorithm::HopcroftKarp => {
+            let res = res.downcast_ref::<BipartiteMatching>().unwrap();
+            let mut cover: Vec<&Vertex> = res.cover.iter().collect();
+            cover.sort();
+
+            println!("Matching: {:?}", res.matching);
+            println!("Matching size: {:}", res.matching.len());
+            println!("Minimum vertex cover: {:?}", cover);
+        }
+        Algorithm::Hungarian => {
+            let res = res.downcast_ref::<Assignment>().unwrap();
+
+            println!("Assignment: {:?}", res.pairs);
+            println!("Assignment cost: {:}", res.cost);
+        }
+        Algorithm::EulerianCircuit | Algorithm::EulerianPath => {
+            match res
+                .downcast_ref::<Result<Path<Vertex, Weight>, EulerError>>()
+                .unwrap()
+            {
+                Ok(trail) => println!("Eulerian trail: {:?}", trail),
+                Err(EulerError::OddDegree(vertices)) => {
+                    println!("No Eulerian trail, odd-degree vertices: {:?}", vertices)
+                }
+                Err(EulerError::Unbalanced(balance)) => {
+                    println!(
+                        "No Eulerian trail, out-degree minus in-degree: {:?}",
+                        balance
+                    )
+                }
+                Err(EulerError::Disconnected(components)) => {
+                    println!(
+                        "No Eulerian trail, edges in several components: {:?}",
+                        components
+                    )
+                }
+            }
+        }
+        Algorithm::ChinesePostman => match res.downcast_ref::<Option<PostmanRoute>>().unwrap() {
+            Some(route) => {
+                println!("Postman route: {:?}", route.circuit);
+                println!("Postman route cost: {:}", route.cost);
+            }
+            None => println!("No postman route: the edges are not connected"),
+        },
+        Algorithm::TopologicalSort => {
+            match res
+                .downcast_ref::<Result<Vec<Vertex>, CycleError>>()
+                .unwrap()
+            {
+                Ok(order) => println!("Topological order: {:?}", order),
+                Err(e) => println!("Cycle: {:?}", e.cycle),
+            }
+        }
+        Algorithm::TspHeldKarp => match res.downcast_ref::<Option<Tour>>().unwrap() {
+            Some(tour) => {
+                println!("Optimal tour: {:?}", tour.vertices);
+                println!("Optimal tour cost: {:}", tour.cost);
+            }
+            None => println!("No tour found"),
+        },
+        Algorithm::FloydWarshall | Algorithm::Johnson => {
+            match res.downcast_ref::<AllPairs>().unwrap() {
+                AllPairs::Distances(m) => print!("{}", m.to_csv()),
+                AllPairs::NegativeCycle(cycle) => println!("Negative cycle: {:?}", cycle),
+            }
+        }
+        Algorithm::TspDoubleTree | Algorithm::TspChristofides | Algorithm::TspLocalSearch => {
+            let tour = res.downcast_ref::<Tour>().unwrap();
+
+            println!("Tour: {:?}", tour.vertices);
+            println!("Tour cost: {:}", tour.cost);
+        }
     };
 }
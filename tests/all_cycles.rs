@@ -0,0 +1,54 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::cycles::simple_cycles::Cycles;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::graph::Path;
+use algorithms_on_graphs::graph::undirected_graph::{Vertex, Weight};
+
+#[test]
+fn all_cycles() {
+    let args = Args {
+        algorithm: Algorithm::AllCycles,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = 20;
+    let current = res.downcast::<Cycles>().unwrap().count();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn all_cycles_max_length() {
+    let args = Args {
+        algorithm: Algorithm::AllCycles,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        max_length: Some(4),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected: Vec<Path<Vertex, Weight>> = vec![
+        vec![(2, 9, -8320), (9, 10, 7163), (10, 11, 6312), (11, 2, 8976)],
+        vec![(4, 5, -9004), (5, 6, 5412), (6, 7, 8840), (7, 4, 7004)],
+    ];
+    let current: Vec<Path<Vertex, Weight>> = res.downcast::<Cycles>().unwrap().collect();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn all_cycles_directed() {
+    let args = Args {
+        algorithm: Algorithm::AllCycles,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        directed: true,
+        max_count: Some(5),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = 5;
+    let current = res.downcast::<Cycles>().unwrap().count();
+    assert_eq!(expected, current);
+}
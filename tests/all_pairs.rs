@@ -0,0 +1,75 @@
+use std::{env, fs, path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::shortest_paths::all_pairs::AllPairs;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::undirected_graph::Weight;
+
+fn args(algorithm: Algorithm, file: &str, directed: bool) -> Args {
+    Args {
+        algorithm,
+        file: PathBuf::from_str(file).unwrap(),
+        directed,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn floyd_warshall_csv() {
+    let csv = env::temp_dir().join("algorithms_on_graphs_floyd_warshall.csv");
+    let args = Args {
+        export_csv: Some(csv.clone()),
+        ..args(
+            Algorithm::FloydWarshall,
+            "./tests/test_dataset/non_negative_weights.txt",
+            false,
+        )
+    };
+    let res = run_cli(&args);
+
+    let expected = ",1,2,3,4,5,6
+1,0,7,9,20,20,11
+2,7,0,10,15,21,12
+3,9,10,0,11,11,2
+4,20,15,11,0,6,13
+5,20,21,11,6,0,9
+6,11,12,2,13,9,0
+";
+    assert_eq!(expected, fs::read_to_string(&csv).unwrap());
+    match res.downcast_ref::<AllPairs>().unwrap() {
+        AllPairs::Distances(m) => {
+            assert_eq!(Some(vec![(1, 3, 9), (3, 4, 11)]), m.path(&1, &4));
+        }
+        AllPairs::NegativeCycle(_) => panic!("No negative cycle expected"),
+    }
+}
+
+#[test]
+fn johnson_matches_floyd_warshall() {
+    let file = "./dataset/input_random_02_10.txt";
+    let fw = run_cli(&args(Algorithm::FloydWarshall, file, true));
+    let johnson = run_cli(&args(Algorithm::Johnson, file, true));
+
+    let fw = fw.downcast_ref::<AllPairs>().unwrap();
+    assert_eq!(fw, johnson.downcast_ref::<AllPairs>().unwrap());
+    match fw {
+        AllPairs::Distances(m) => {
+            assert_eq!(Some(-314), m.distance(&1, &3));
+            assert_eq!(None, m.distance(&2, &1));
+        }
+        AllPairs::NegativeCycle(_) => panic!("No negative cycle expected"),
+    }
+}
+
+#[test]
+fn negative_cycle() {
+    for algorithm in [Algorithm::FloydWarshall, Algorithm::Johnson] {
+        let res = run_cli(&args(algorithm, "./dataset/input_random_05_20.txt", true));
+
+        match res.downcast_ref::<AllPairs>().unwrap() {
+            AllPairs::NegativeCycle(cycle) => {
+                assert!(cycle.iter().map(|e| e.2).sum::<Weight>() < 0)
+            }
+            AllPairs::Distances(_) => panic!("Negative cycle expected"),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::shortest_paths::astar::AStarResult;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+
+#[test]
+fn astar() {
+    let args = Args {
+        algorithm: Algorithm::AStar,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        target: Some(5),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Option<AStarResult>>().unwrap();
+    let current = current.as_ref().unwrap();
+    assert_eq!(&vec![(1, 3, 9), (3, 6, 2), (6, 5, 9)], &current.path);
+    assert_eq!(20, current.cost);
+}
+
+#[test]
+fn astar_landmarks() {
+    let args = Args {
+        algorithm: Algorithm::AStar,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        target: Some(5),
+        landmarks: Some(2),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Option<AStarResult>>().unwrap();
+    let current = current.as_ref().unwrap();
+    assert_eq!(&vec![(1, 3, 9), (3, 6, 2), (6, 5, 9)], &current.path);
+    assert_eq!(20, current.cost);
+}
+
+#[test]
+fn astar_unreachable_target() {
+    let args = Args {
+        algorithm: Algorithm::AStar,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(5),
+        target: Some(1),
+        directed: true,
+        landmarks: Some(1),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Option<AStarResult>>().unwrap();
+    assert_eq!(&None, current);
+}
@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::graph::Path;
+use algorithms_on_graphs::graph::undirected_graph::{Vertex, Weight};
+
+fn args(algorithm: Algorithm, file: &str) -> Args {
+    Args {
+        algorithm,
+        file: PathBuf::from_str(file).unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn bridges() {
+    let res = run_cli(&args(
+        Algorithm::Bridges,
+        "./dataset/input_random_05_20.txt",
+    ));
+
+    let expected: Path<Vertex, Weight> = vec![(1, 2, -2650), (18, 19, 3302), (19, 20, -9459)];
+    assert_eq!(
+        &expected,
+        res.downcast_ref::<Path<Vertex, Weight>>().unwrap()
+    );
+}
+
+#[test]
+fn articulation_points() {
+    let res = run_cli(&args(
+        Algorithm::ArticulationPoints,
+        "./dataset/input_random_05_20.txt",
+    ));
+
+    let expected: HashSet<Vertex> = HashSet::from([2, 18, 19]);
+    assert_eq!(&expected, res.downcast_ref::<HashSet<Vertex>>().unwrap());
+}
+
+#[test]
+fn tree_bridges_and_articulation_points() {
+    // A spanning tree: every edge is a bridge, every inner vertex a cut vertex
+    let file = "./dataset/input_random_01_10.txt";
+    let bridges = run_cli(&args(Algorithm::Bridges, file));
+    let points = run_cli(&args(Algorithm::ArticulationPoints, file));
+
+    assert_eq!(
+        9,
+        bridges
+            .downcast_ref::<Path<Vertex, Weight>>()
+            .unwrap()
+            .len()
+    );
+    assert_eq!(
+        &(2..=9).collect::<HashSet<Vertex>>(),
+        points.downcast_ref::<HashSet<Vertex>>().unwrap()
+    );
+}
@@ -0,0 +1,29 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::euler::chinese_postman::PostmanRoute;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+
+fn args(file: &str) -> Args {
+    Args {
+        algorithm: Algorithm::ChinesePostman,
+        file: PathBuf::from_str(file).unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn chinese_postman() {
+    // Edges weigh 83; the odd vertices 1, 2, 4 and 6 are best paired as 1-2
+    // and 4-3-6
+    let res = run_cli(&args("./tests/test_dataset/non_negative_weights.txt"));
+    let route = res
+        .downcast_ref::<Option<PostmanRoute>>()
+        .unwrap()
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(103, route.cost);
+    assert_eq!(12, route.circuit.len());
+    assert_eq!(1, route.circuit[0].0);
+    assert_eq!(1, route.circuit[11].1);
+}
@@ -9,7 +9,7 @@ fn wrong_header() {
     let args = Args {
         algorithm: Algorithm::IsAcyclic,
         file: PathBuf::from_str("./tests/test_dataset/wrong_header.txt").unwrap(),
-        start: None,
+        ..Default::default()
     };
     run_cli(&args);
 }
@@ -20,7 +20,7 @@ fn wrong_first_vertex() {
     let args = Args {
         algorithm: Algorithm::IsAcyclic,
         file: PathBuf::from_str("./tests/test_dataset/wrong_first_vertex.txt").unwrap(),
-        start: None,
+        ..Default::default()
     };
     run_cli(&args);
 }
@@ -31,7 +31,7 @@ fn wrong_second_vertex() {
     let args = Args {
         algorithm: Algorithm::IsAcyclic,
         file: PathBuf::from_str("./tests/test_dataset/wrong_second_vertex.txt").unwrap(),
-        start: None,
+        ..Default::default()
     };
     run_cli(&args);
 }
@@ -42,7 +42,7 @@ fn wrong_weight() {
     let args = Args {
         algorithm: Algorithm::IsAcyclic,
         file: PathBuf::from_str("./tests/test_dataset/wrong_weight.txt").unwrap(),
-        start: None,
+        ..Default::default()
     };
     run_cli(&args);
 }
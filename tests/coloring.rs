@@ -0,0 +1,32 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::coloring::proper_coloring::Coloring;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args, ColoringStrategy};
+
+#[test]
+fn coloring() {
+    let args = Args {
+        algorithm: Algorithm::Coloring,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Coloring>().unwrap();
+    assert_eq!(2, current.num_colors);
+}
+
+#[test]
+fn coloring_exact() {
+    let args = Args {
+        algorithm: Algorithm::Coloring,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        coloring: Some(ColoringStrategy::Exact),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Coloring>().unwrap();
+    assert_eq!(20, current.colors.len());
+    assert_eq!(3, current.num_colors);
+}
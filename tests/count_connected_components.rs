@@ -9,7 +9,7 @@ fn count_connected_components() {
     let args = Args {
         algorithm: Algorithm::CountConnectedComponents,
         file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
-        start: None,
+        ..Default::default()
     };
     let res = run_cli(&args);
 
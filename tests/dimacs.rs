@@ -0,0 +1,100 @@
+use std::{env, fs, path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::flow::MaxFlow;
+use algorithms_on_graphs::algorithms::shortest_paths::astar::AStarResult;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args, InputFormat};
+use algorithms_on_graphs::graph::graph::Path;
+use algorithms_on_graphs::graph::undirected_graph::{Vertex, Weight};
+
+#[test]
+fn dimacs_shortest_path() {
+    let args = Args {
+        algorithm: Algorithm::AStar,
+        file: PathBuf::from_str("./tests/test_dataset/roads.gr").unwrap(),
+        start: Some(1),
+        target: Some(5),
+        directed: true,
+        input_format: Some(InputFormat::Dimacs),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Option<AStarResult>>().unwrap();
+    let expected = vec![(1, 3, 1), (3, 2, 2), (2, 4, 1), (4, 5, 3)];
+    assert_eq!(expected, current.as_ref().unwrap().path);
+    assert_eq!(7, current.as_ref().unwrap().cost);
+}
+
+#[test]
+fn dimacs_max_flow_terminals() {
+    let args = Args {
+        algorithm: Algorithm::StConnected,
+        file: PathBuf::from_str("./tests/test_dataset/flow.max").unwrap(),
+        directed: true,
+        input_format: Some(InputFormat::Dimacs),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let path = res
+        .downcast_ref::<Option<Path<Vertex, Weight>>>()
+        .unwrap()
+        .as_ref()
+        .unwrap();
+    assert_eq!(1, path[0].0);
+    assert_eq!(6, path[path.len() - 1].1);
+}
+
+#[test]
+fn export_dimacs_max_flow() {
+    let dimacs = env::temp_dir().join("algorithms_on_graphs_dinic.max");
+    let args = Args {
+        algorithm: Algorithm::Dinic,
+        file: PathBuf::from_str("./tests/test_dataset/flow.max").unwrap(),
+        input_format: Some(InputFormat::Dimacs),
+        export_dimacs: Some(dimacs.clone()),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = "p max 6 8
+n 1 s
+n 6 t
+a 1 2 16
+a 1 3 13
+a 2 4 12
+a 3 2 4
+a 3 5 14
+a 4 3 9
+a 4 6 20
+a 5 6 4
+";
+    assert_eq!(expected, fs::read_to_string(&dimacs).unwrap());
+    assert_eq!(16, res.downcast_ref::<MaxFlow>().unwrap().value);
+}
+
+#[test]
+fn export_dimacs_edge_list() {
+    let dimacs = env::temp_dir().join("algorithms_on_graphs_prim.col");
+    let args = Args {
+        algorithm: Algorithm::Prim,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        export_dimacs: Some(dimacs.clone()),
+        ..Default::default()
+    };
+    run_cli(&args);
+
+    let expected = "p edge 6 9
+e 1 2
+e 1 3
+e 1 6
+e 2 3
+e 2 4
+e 3 4
+e 3 6
+e 4 5
+e 5 6
+";
+    assert_eq!(expected, fs::read_to_string(&dimacs).unwrap());
+}
@@ -0,0 +1,58 @@
+use std::{env, fs, path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::cycles::simple_cycles::Cycles;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::graph::Path;
+use algorithms_on_graphs::graph::undirected_graph::{Vertex, Weight};
+
+#[test]
+fn export_dot_prim() {
+    let dot = env::temp_dir().join("algorithms_on_graphs_prim.dot");
+    let args = Args {
+        algorithm: Algorithm::Prim,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        start: Some(1),
+        export_dot: Some(dot.clone()),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = fs::read_to_string(&dot).unwrap();
+    assert_eq!(9, res.downcast_ref::<Path<Vertex, Weight>>().unwrap().len());
+    assert!(current.starts_with("graph {\n"));
+    assert!(current.contains("    1 -- 2 [label=\"4993\", style=bold, penwidth=3];\n"));
+    assert_eq!(9, current.matches("style=bold").count());
+}
+
+#[test]
+fn export_dot_components() {
+    let dot = env::temp_dir().join("algorithms_on_graphs_components.dot");
+    let args = Args {
+        algorithm: Algorithm::CountConnectedComponents,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        export_dot: Some(dot.clone()),
+        ..Default::default()
+    };
+    run_cli(&args);
+
+    let current = fs::read_to_string(&dot).unwrap();
+    assert_eq!(20, current.matches("style=filled").count());
+}
+
+#[test]
+fn export_dot_keeps_cycles() {
+    let dot = env::temp_dir().join("algorithms_on_graphs_cycles.dot");
+    let args = Args {
+        algorithm: Algorithm::AllCycles,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        max_length: Some(4),
+        export_dot: Some(dot.clone()),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let cycles: Vec<Path<Vertex, Weight>> = res.downcast::<Cycles>().unwrap().collect();
+    let current = fs::read_to_string(&dot).unwrap();
+    assert_eq!(2, cycles.len());
+    assert!(current.contains("color=red"));
+}
@@ -0,0 +1,48 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::euler::EulerError;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::graph::Path;
+use algorithms_on_graphs::graph::undirected_graph::{Vertex, Weight};
+
+fn args(algorithm: Algorithm, file: &str) -> Args {
+    Args {
+        algorithm,
+        file: PathBuf::from_str(file).unwrap(),
+        ..Default::default()
+    }
+}
+
+fn trail(res: &dyn std::any::Any) -> &Result<Path<Vertex, Weight>, EulerError> {
+    res.downcast_ref::<Result<Path<Vertex, Weight>, EulerError>>()
+        .unwrap()
+}
+
+#[test]
+fn path_between_odd_vertices() {
+    let file = "./tests/test_dataset/k23.txt";
+    let circuit = run_cli(&args(Algorithm::EulerianCircuit, file));
+    let path = run_cli(&args(Algorithm::EulerianPath, file));
+
+    assert_eq!(
+        &Err(EulerError::OddDegree(vec![1, 2])),
+        trail(circuit.as_ref())
+    );
+    let path = trail(path.as_ref()).as_ref().unwrap();
+    assert_eq!(6, path.len());
+    assert_eq!(1, path[0].0);
+    assert_eq!(2, path[5].1);
+}
+
+#[test]
+fn too_many_odd_vertices() {
+    let res = run_cli(&args(
+        Algorithm::EulerianPath,
+        "./tests/test_dataset/non_negative_weights.txt",
+    ));
+
+    assert_eq!(
+        &Err(EulerError::OddDegree(vec![1, 2, 4, 6])),
+        trail(res.as_ref())
+    );
+}
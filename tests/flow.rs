@@ -0,0 +1,86 @@
+use std::collections::HashSet;
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::flow::MaxFlow;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args, InputFormat};
+
+fn args(algorithm: Algorithm) -> Args {
+    Args {
+        algorithm,
+        file: PathBuf::from_str("./tests/test_dataset/flow.max").unwrap(),
+        directed: true,
+        input_format: Some(InputFormat::Dimacs),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn dimacs_max_flow() {
+    for algorithm in [
+        Algorithm::EdmondsKarp,
+        Algorithm::Dinic,
+        Algorithm::PushRelabel,
+    ] {
+        let res = run_cli(&args(algorithm));
+        let res = res.downcast_ref::<MaxFlow>().unwrap();
+
+        assert_eq!(16, res.value);
+        assert_eq!(HashSet::from([1, 2, 3, 5]), res.source_side);
+        assert_eq!(vec![(2, 4, 12), (5, 6, 4)], res.cut);
+    }
+}
+
+#[test]
+fn dimacs_max_flow_ignores_directed_flag() {
+    for algorithm in [
+        Algorithm::EdmondsKarp,
+        Algorithm::Dinic,
+        Algorithm::PushRelabel,
+    ] {
+        let res = run_cli(&Args {
+            directed: false,
+            ..args(algorithm)
+        });
+        let res = res.downcast_ref::<MaxFlow>().unwrap();
+
+        assert_eq!(16, res.value);
+        assert_eq!(vec![(2, 4, 12), (5, 6, 4)], res.cut);
+    }
+}
+
+#[test]
+fn undirected_max_flow() {
+    let mut values = vec![];
+
+    for algorithm in [
+        Algorithm::EdmondsKarp,
+        Algorithm::Dinic,
+        Algorithm::PushRelabel,
+    ] {
+        let args = Args {
+            file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+            start: Some(1),
+            target: Some(5),
+            directed: false,
+            input_format: None,
+            ..args(algorithm)
+        };
+        let res = run_cli(&args);
+        let res = res.downcast_ref::<MaxFlow>().unwrap();
+
+        assert_eq!(res.value, res.cut.iter().map(|e| e.2).sum());
+        values.push(res.value);
+    }
+    assert_eq!(vec![15, 15, 15], values);
+}
+
+#[test]
+#[should_panic]
+fn missing_sink() {
+    run_cli(&Args {
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        input_format: None,
+        ..args(Algorithm::Dinic)
+    });
+}
@@ -0,0 +1,117 @@
+use std::{env, fs};
+
+use algorithms_on_graphs::cli::cli::{
+    run_cli, run_generate, Algorithm, Args, Cli, Command, GenerateArgs, RandomModel,
+};
+use clap::Parser;
+
+fn generate_args(model: RandomModel, output: &str) -> GenerateArgs {
+    GenerateArgs {
+        model,
+        vertices: 30,
+        edges: Some(60),
+        probability: Some(0.2),
+        min_weight: -50,
+        max_weight: 50,
+        seed: 11,
+        output: Some(env::temp_dir().join(output)),
+    }
+}
+
+fn count_args(generate: &GenerateArgs) -> Args {
+    Args {
+        algorithm: Algorithm::CountConnectedComponents,
+        file: generate.output.clone().unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn generate_connected() {
+    let generate = generate_args(RandomModel::Connected, "algorithms_on_graphs_connected.txt");
+    let out = run_generate(&generate);
+
+    assert!(out.starts_with("30 60\n"));
+    assert_eq!(
+        out,
+        fs::read_to_string(generate.output.as_ref().unwrap()).unwrap()
+    );
+    for line in out.lines().skip(1) {
+        let w: i32 = line.split_whitespace().nth(2).unwrap().parse().unwrap();
+        assert!((-50..=50).contains(&w));
+    }
+
+    let res = run_cli(&count_args(&generate));
+    assert_eq!(&1, res.downcast_ref::<usize>().unwrap());
+}
+
+#[test]
+fn generate_gnm() {
+    let generate = generate_args(RandomModel::Gnm, "algorithms_on_graphs_gnm.txt");
+    let out = run_generate(&generate);
+
+    assert!(out.starts_with("30 60\n"));
+    assert_eq!(61, out.lines().count());
+}
+
+#[test]
+fn generate_gnp_is_reproducible() {
+    let generate = generate_args(RandomModel::Gnp, "algorithms_on_graphs_gnp.txt");
+
+    assert_eq!(run_generate(&generate), run_generate(&generate));
+    assert_ne!(
+        run_generate(&generate),
+        run_generate(&GenerateArgs {
+            seed: 12,
+            ..generate.clone()
+        })
+    );
+}
+
+#[test]
+fn parse_generate_subcommand() {
+    let cli = Cli::try_parse_from([
+        "algorithms_on_graphs",
+        "generate",
+        "--model",
+        "gnm",
+        "-n",
+        "10",
+        "-m",
+        "12",
+        "--min-weight",
+        "-3",
+        "--seed",
+        "4",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Command::Generate(generate)) => {
+            assert_eq!(10, generate.vertices);
+            assert_eq!(Some(12), generate.edges);
+            assert_eq!(-3, generate.min_weight);
+            assert_eq!(10000, generate.max_weight);
+            assert_eq!(4, generate.seed);
+        }
+        _ => panic!("Expected the generate subcommand"),
+    }
+}
+
+#[test]
+fn parse_algorithm_without_subcommand() {
+    let cli =
+        Cli::try_parse_from(["algorithms_on_graphs", "-a", "prim", "-f", "graph.txt"]).unwrap();
+
+    assert!(cli.command.is_none());
+    assert!(matches!(cli.args.unwrap().algorithm, Algorithm::Prim));
+}
+
+#[test]
+#[should_panic]
+fn generate_gnp_without_probability() {
+    run_generate(&GenerateArgs {
+        probability: None,
+        ..generate_args(RandomModel::Gnp, "algorithms_on_graphs_gnp_missing.txt")
+    });
+}
@@ -0,0 +1,36 @@
+use std::collections::HashSet;
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::matching::hopcroft_karp::BipartiteMatching;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::undirected_graph::Vertex;
+
+fn args(file: &str) -> Args {
+    Args {
+        algorithm: Algorithm::HopcroftKarp,
+        file: PathBuf::from_str(file).unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn jobs() {
+    // Workers 1-5 and jobs 6-10; job 10 is only wanted by worker 4
+    let res = run_cli(&args("./tests/test_dataset/jobs.txt"));
+    let res = res.downcast_ref::<BipartiteMatching>().unwrap();
+
+    assert_eq!(5, res.matching.len());
+    let workers: HashSet<Vertex> = res.matching.iter().map(|e| e.0).collect();
+    assert_eq!(HashSet::from([1, 2, 3, 4, 5]), workers);
+    assert!(res.matching.contains(&(4, 10, 6)));
+    assert_eq!(5, res.cover.len());
+}
+
+#[test]
+fn path() {
+    let res = run_cli(&args("./dataset/input_random_01_10.txt"));
+    let res = res.downcast_ref::<BipartiteMatching>().unwrap();
+
+    assert_eq!(5, res.matching.len());
+    assert_eq!(5, res.cover.len());
+}
@@ -0,0 +1,51 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::matching::hungarian::Assignment;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args, InputFormat};
+
+fn args(file: &str, input_format: Option<InputFormat>, maximize: bool) -> Args {
+    Args {
+        algorithm: Algorithm::Hungarian,
+        file: PathBuf::from_str(file).unwrap(),
+        input_format,
+        maximize,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn matrix_minimize() {
+    let res = run_cli(&args(
+        "./tests/test_dataset/costs.txt",
+        Some(InputFormat::Matrix),
+        false,
+    ));
+
+    let expected = Assignment {
+        pairs: vec![(0, 1), (1, 0), (2, 2), (3, 3)],
+        cost: 13,
+    };
+    assert_eq!(&expected, res.downcast_ref::<Assignment>().unwrap());
+}
+
+#[test]
+fn matrix_maximize() {
+    let res = run_cli(&args(
+        "./tests/test_dataset/costs.txt",
+        Some(InputFormat::Matrix),
+        true,
+    ));
+
+    assert_eq!(33, res.downcast_ref::<Assignment>().unwrap().cost);
+}
+
+#[test]
+fn complete_bipartite_edge_list() {
+    let res = run_cli(&args("./tests/test_dataset/k23.txt", None, false));
+
+    let expected = Assignment {
+        pairs: vec![(1, 5), (2, 4)],
+        cost: 5,
+    };
+    assert_eq!(&expected, res.downcast_ref::<Assignment>().unwrap());
+}
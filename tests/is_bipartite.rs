@@ -0,0 +1,37 @@
+use std::collections::HashSet;
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::bipartite::is_bipartite::Bipartiteness;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+
+#[test]
+fn is_bipartite() {
+    let args = Args {
+        algorithm: Algorithm::IsBipartite,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = &Bipartiteness::Bipartite(
+        HashSet::from([1, 3, 5, 7, 9]),
+        HashSet::from([2, 4, 6, 8, 10]),
+    );
+    let current = res.downcast_ref::<Bipartiteness>().unwrap();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn is_not_bipartite() {
+    let args = Args {
+        algorithm: Algorithm::IsBipartite,
+        file: PathBuf::from_str("./dataset/input_random_05_20.txt").unwrap(),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    match res.downcast_ref::<Bipartiteness>().unwrap() {
+        Bipartiteness::OddCycle(cycle) => assert_eq!(1, cycle.len() % 2),
+        _ => panic!("Expected an odd cycle"),
+    }
+}
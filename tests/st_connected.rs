@@ -0,0 +1,38 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::graph::Path;
+use algorithms_on_graphs::graph::undirected_graph::{Vertex, Weight};
+
+#[test]
+fn st_connected() {
+    let args = Args {
+        algorithm: Algorithm::StConnected,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        start: Some(3),
+        target: Some(7),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected_path = &Some(vec![
+        (3, 4, 8856),
+        (4, 5, -433),
+        (5, 6, 6590),
+        (6, 7, -7462),
+    ]);
+    let current_path = res.downcast_ref::<Option<Path<Vertex, Weight>>>().unwrap();
+    assert_eq!(expected_path, current_path);
+}
+
+#[test]
+#[should_panic]
+fn st_connected_missing_target() {
+    let args = Args {
+        algorithm: Algorithm::StConnected,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        start: Some(3),
+        ..Default::default()
+    };
+    run_cli(&args);
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::undirected_graph::Vertex;
+
+fn args(algorithm: Algorithm, file: &str) -> Args {
+    Args {
+        algorithm,
+        file: PathBuf::from_str(file).unwrap(),
+        directed: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn strongly_connected_components() {
+    let res = run_cli(&args(
+        Algorithm::StronglyConnectedComponents,
+        "./dataset/input_random_05_20.txt",
+    ));
+
+    let expected: HashMap<Vertex, usize> = (1..=20)
+        .map(|v| match v {
+            1 => (v, 0),
+            19 => (v, 2),
+            20 => (v, 3),
+            _ => (v, 1),
+        })
+        .collect();
+    assert_eq!(
+        &expected,
+        res.downcast_ref::<HashMap<Vertex, usize>>().unwrap()
+    );
+}
+
+#[test]
+fn weakly_connected_components() {
+    let res = run_cli(&args(
+        Algorithm::WeaklyConnectedComponents,
+        "./dataset/input_random_05_20.txt",
+    ));
+
+    let labels = res.downcast_ref::<HashMap<Vertex, usize>>().unwrap();
+    assert_eq!(20, labels.len());
+    assert!(labels.values().all(|l| *l == 0));
+}
+
+#[test]
+fn directed_path_has_singleton_components() {
+    let res = run_cli(&args(
+        Algorithm::StronglyConnectedComponents,
+        "./dataset/input_random_01_10.txt",
+    ));
+
+    let expected: HashMap<Vertex, usize> = (1..=10).map(|v| (v, v - 1)).collect();
+    assert_eq!(
+        &expected,
+        res.downcast_ref::<HashMap<Vertex, usize>>().unwrap()
+    );
+}
@@ -0,0 +1,80 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::topological_sort::CycleError;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args, InputFormat, TopologicalOrder};
+use algorithms_on_graphs::graph::undirected_graph::Vertex;
+
+fn args(file: &str) -> Args {
+    Args {
+        algorithm: Algorithm::TopologicalSort,
+        file: PathBuf::from_str(file).unwrap(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn topological_order() {
+    let res = run_cli(&args("./dataset/input_random_01_10.txt"));
+
+    assert_eq!(
+        &Ok((1..=10).collect()),
+        res.downcast_ref::<Result<Vec<Vertex>, CycleError>>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn cycle() {
+    let res = run_cli(&args("./dataset/input_random_05_20.txt"));
+
+    let expected = CycleError {
+        cycle: vec![
+            (2, 3, -3425),
+            (3, 4, -2263),
+            (4, 7, 7004),
+            (7, 8, -5702),
+            (8, 9, 298),
+            (9, 2, -8320),
+        ],
+    };
+    assert_eq!(
+        &Err(expected),
+        res.downcast_ref::<Result<Vec<Vertex>, CycleError>>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn order_strategies() {
+    // 1 -> 4 and 2 -> 3, read without -d
+    let order = |topological_order| {
+        let res = run_cli(&Args {
+            topological_order,
+            ..args("./tests/test_dataset/dag.txt")
+        });
+        res.downcast_ref::<Result<Vec<Vertex>, CycleError>>()
+            .unwrap()
+            .clone()
+    };
+
+    assert_eq!(Ok(vec![1, 2, 3, 4]), order(None));
+    assert_eq!(Ok(vec![1, 2, 4, 3]), order(Some(TopologicalOrder::Kahn)));
+    assert_eq!(Ok(vec![2, 3, 1, 4]), order(Some(TopologicalOrder::Dfs)));
+}
+
+#[test]
+fn dimacs_cycle() {
+    let res = run_cli(&Args {
+        input_format: Some(InputFormat::Dimacs),
+        ..args("./tests/test_dataset/roads.gr")
+    });
+
+    let expected = CycleError {
+        cycle: vec![(1, 2, 4), (2, 4, 1), (4, 5, 3), (5, 1, 1)],
+    };
+    assert_eq!(
+        &Err(expected),
+        res.downcast_ref::<Result<Vec<Vertex>, CycleError>>()
+            .unwrap()
+    );
+}
@@ -0,0 +1,74 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::tsp::tour::Tour;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+
+#[test]
+fn tsp_double_tree() {
+    let args = Args {
+        algorithm: Algorithm::TspDoubleTree,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = &Tour {
+        vertices: vec![1, 2, 3, 6, 5, 4],
+        cost: 54,
+    };
+    let current = res.downcast_ref::<Tour>().unwrap();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn tsp_christofides() {
+    let args = Args {
+        algorithm: Algorithm::TspChristofides,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = &Tour {
+        vertices: vec![1, 2, 4, 5, 6, 3],
+        cost: 48,
+    };
+    let current = res.downcast_ref::<Tour>().unwrap();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn tsp_held_karp() {
+    let args = Args {
+        algorithm: Algorithm::TspHeldKarp,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = &Some(Tour {
+        vertices: vec![1, 3, 6, 5, 4, 2],
+        cost: 48,
+    });
+    let current = res.downcast_ref::<Option<Tour>>().unwrap();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn tsp_local_search() {
+    let args = Args {
+        algorithm: Algorithm::TspLocalSearch,
+        file: PathBuf::from_str("./tests/test_dataset/non_negative_weights.txt").unwrap(),
+        start: Some(1),
+        time_limit: Some(10),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Tour>().unwrap();
+    assert_eq!(1, current.vertices[0]);
+    assert_eq!(48, current.cost);
+}
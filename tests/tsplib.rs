@@ -0,0 +1,40 @@
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::algorithms::tsp::tour::Tour;
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args, InputFormat};
+
+#[test]
+fn tsplib_held_karp() {
+    let args = Args {
+        algorithm: Algorithm::TspHeldKarp,
+        file: PathBuf::from_str("./tests/test_dataset/burma14.tsp").unwrap(),
+        start: Some(1),
+        input_format: Some(InputFormat::Tsplib),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res
+        .downcast_ref::<Option<Tour>>()
+        .unwrap()
+        .as_ref()
+        .unwrap();
+    assert_eq!(14, current.vertices.len());
+    assert_eq!(3323, current.cost);
+}
+
+#[test]
+fn tsplib_christofides() {
+    let args = Args {
+        algorithm: Algorithm::TspChristofides,
+        file: PathBuf::from_str("./tests/test_dataset/burma14.tsp").unwrap(),
+        start: Some(1),
+        input_format: Some(InputFormat::Tsplib),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<Tour>().unwrap();
+    assert_eq!(14, current.vertices.len());
+    assert!(2 * current.cost <= 3 * 3323);
+}
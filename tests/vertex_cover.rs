@@ -0,0 +1,35 @@
+use std::collections::HashSet;
+use std::{path::PathBuf, str::FromStr};
+
+use algorithms_on_graphs::cli::cli::{run_cli, Algorithm, Args};
+use algorithms_on_graphs::graph::undirected_graph::Vertex;
+
+#[test]
+fn vertex_cover() {
+    let args = Args {
+        algorithm: Algorithm::VertexCover,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let expected = &HashSet::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let current = res.downcast_ref::<HashSet<Vertex>>().unwrap();
+    assert_eq!(expected, current);
+}
+
+#[test]
+fn minimum_vertex_cover() {
+    let args = Args {
+        algorithm: Algorithm::MinimumVertexCover,
+        file: PathBuf::from_str("./dataset/input_random_01_10.txt").unwrap(),
+        ..Default::default()
+    };
+    let res = run_cli(&args);
+
+    let current = res.downcast_ref::<HashSet<Vertex>>().unwrap();
+    assert_eq!(5, current.len());
+    for v in 1..10 {
+        assert!(current.contains(&v) || current.contains(&(v + 1)));
+    }
+}